@@ -0,0 +1,105 @@
+//! Derive macros of Robotic Lib, re-exported by `robotics_lib::runner`.
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use syn::spanned::Spanned;
+use syn::{parse_macro_input, Data, DeriveInput, Error, Index};
+
+/// Derives `robotics_lib::runner::RobotState` by delegating the six accessors to one field of the struct.
+///
+/// The field is the one marked with `#[robot]`, the attribute can be left out when the struct has a single field.
+/// The field can be a `Robot` or anything else that implements `RobotState`.
+///
+/// # Example
+/// ```ignore
+/// use robotics_lib::runner::{Robot, RobotState};
+///
+/// #[derive(RobotState)]
+/// struct MyRobot {
+///     #[robot]
+///     robot: Robot,
+///     plan: Vec<usize>,
+/// }
+/// ```
+#[proc_macro_derive(RobotState, attributes(robot))]
+pub fn derive_robot_state(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    match robot_state(&input) {
+        | Ok(tokens) => tokens.into(),
+        | Err(error) => error.to_compile_error().into(),
+    }
+}
+
+fn robot_state(input: &DeriveInput) -> Result<TokenStream2, Error> {
+    let field = robot_field(input)?;
+    let name = &input.ident;
+    let (impl_generics, type_generics, where_clause) = input.generics.split_for_impl();
+    let state = quote!(::robotics_lib::runner::RobotState);
+
+    Ok(quote! {
+        impl #impl_generics #state for #name #type_generics #where_clause {
+            fn get_energy(&self) -> &::robotics_lib::energy::Energy {
+                #state::get_energy(&self.#field)
+            }
+            fn get_energy_mut(&mut self) -> &mut ::robotics_lib::energy::Energy {
+                #state::get_energy_mut(&mut self.#field)
+            }
+            fn get_coordinate(&self) -> &::robotics_lib::world::coordinates::Coordinate {
+                #state::get_coordinate(&self.#field)
+            }
+            fn get_coordinate_mut(&mut self) -> &mut ::robotics_lib::world::coordinates::Coordinate {
+                #state::get_coordinate_mut(&mut self.#field)
+            }
+            fn get_backpack(&self) -> &::robotics_lib::runner::backpack::BackPack {
+                #state::get_backpack(&self.#field)
+            }
+            fn get_backpack_mut(&mut self) -> &mut ::robotics_lib::runner::backpack::BackPack {
+                #state::get_backpack_mut(&mut self.#field)
+            }
+        }
+    })
+}
+
+/// Finds the field the accessors delegate to, as the tokens that follow `self.`
+fn robot_field(input: &DeriveInput) -> Result<TokenStream2, Error> {
+    let fields = match &input.data {
+        | Data::Struct(data) => &data.fields,
+        | _ => {
+            return Err(Error::new(
+                input.ident.span(),
+                "RobotState can only be derived for structs",
+            ))
+        }
+    };
+
+    let marked: Vec<_> = fields
+        .iter()
+        .enumerate()
+        .filter(|(_, field)| field.attrs.iter().any(|attr| attr.path().is_ident("robot")))
+        .collect();
+    let (index, field) = match marked.as_slice() {
+        | [marked] => *marked,
+        | [] if fields.len() == 1 => (0, fields.iter().next().unwrap()),
+        | [] => {
+            return Err(Error::new(
+                input.ident.span(),
+                "RobotState needs the field holding the robot to be marked with #[robot]",
+            ))
+        }
+        | [_, second, ..] => {
+            return Err(Error::new(
+                second.1.span(),
+                "only one field can be marked with #[robot]",
+            ))
+        }
+    };
+
+    Ok(match &field.ident {
+        | Some(ident) => quote!(#ident),
+        | None => {
+            let index = Index::from(index);
+            quote!(#index)
+        }
+    })
+}
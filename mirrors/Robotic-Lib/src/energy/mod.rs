@@ -1,3 +1,5 @@
+use serde::{Deserialize, Serialize};
+
 use crate::utils::LibError;
 use crate::utils::LibError::*;
 
@@ -9,6 +11,7 @@ pub(crate) const MAX_ENERGY_LEVEL: usize = 1000;
 /// # Fields
 ///
 /// - `energy_level`: An `usize` that holds the energy level of the robot.
+/// - `max_energy_level`: An `usize` that holds the maximum energy level of the robot (default: 1000).
 ///
 /// # Usage
 ///
@@ -27,9 +30,10 @@ pub(crate) const MAX_ENERGY_LEVEL: usize = 1000;
 /// #Remarks
 /// - The energy level is set to 0 by default
 /// - Consume energy is pub(crate) because it should be used only by the robot
-#[derive(Debug, PartialEq)]
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
 pub struct Energy {
     energy_level: usize,
+    max_energy_level: usize,
 }
 
 impl Default for Energy {
@@ -40,8 +44,13 @@ impl Default for Energy {
 
 impl Energy {
     pub(crate) fn new(energy_level: usize) -> Self {
+        Self::with_max(energy_level, MAX_ENERGY_LEVEL)
+    }
+
+    pub(crate) fn with_max(energy_level: usize, max_energy_level: usize) -> Self {
         Energy {
-            energy_level: std::cmp::min(energy_level, MAX_ENERGY_LEVEL),
+            energy_level: std::cmp::min(energy_level, max_energy_level),
+            max_energy_level,
         }
     }
 
@@ -49,6 +58,10 @@ impl Energy {
         self.energy_level
     }
 
+    pub fn get_max_energy_level(&self) -> usize {
+        self.max_energy_level
+    }
+
     /// Consumes the energy needed
     ///
     /// # Arguments  
@@ -97,6 +110,6 @@ impl Energy {
     /// - The energy level is increased by the energy to add
     /// - If the energy level is greater than MAX_ENERGY_LEVEL, the energy level is set to MAX_ENERGY_LEVEL
     pub(crate) fn recharge_energy(&mut self, energy_to_add: usize) {
-        self.energy_level = std::cmp::min(MAX_ENERGY_LEVEL, self.energy_level + energy_to_add);
+        self.energy_level = std::cmp::min(self.max_energy_level, self.energy_level + energy_to_add);
     }
 }
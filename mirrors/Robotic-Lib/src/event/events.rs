@@ -1,7 +1,9 @@
 use std::fmt;
 use std::fmt::{Display, Formatter};
+use std::hash::{Hash, Hasher};
+use std::mem;
 
-use crate::world::environmental_conditions::EnvironmentalConditions;
+use crate::world::environmental_conditions::{EnvironmentalConditions, WeatherType};
 use crate::world::tile::{Content, Tile};
 
 /// Represent an [Event] that occurred in a Robot.
@@ -9,13 +11,11 @@ use crate::world::tile::{Content, Tile};
 /// # Example
 /// You can listen to the events in the `handle_event` function of the [crate::runner::Runnable] trait
 /// ```rust
-///  use robotics_lib::energy::Energy;
 ///  use robotics_lib::event::events::Event;
-///  use robotics_lib::runner::{Robot, Runnable};
-///  use robotics_lib::runner::backpack::BackPack;
-///  use robotics_lib::world::coordinates::Coordinate;
+///  use robotics_lib::runner::{Robot, RobotState, Runnable};
 ///  use robotics_lib::world::World;
 ///
+///  #[derive(RobotState)]
 ///  struct MyRobot(Robot);
 ///
 ///  impl Runnable for MyRobot {
@@ -27,31 +27,10 @@ use crate::world::tile::{Content, Tile};
 ///         // consume the event in your GUI
 ///         println!("{:?}", event);
 ///     }
-///
-///     fn get_energy(&self) -> &Energy {
-///         &self.0.energy
-///     }
-///     fn get_energy_mut(&mut self) -> &mut Energy {
-///         &mut self.0.energy
-///     }
-///
-///     fn get_coordinate(&self) -> &Coordinate {
-///         &self.0.coordinate
-///     }
-///     fn get_coordinate_mut(&mut self) -> &mut Coordinate {
-///         &mut self.0.coordinate
-///     }
-///
-///     fn get_backpack(&self) -> &BackPack {
-///         &self.0.backpack
-///     }
-///     fn get_backpack_mut(&mut self) -> &mut BackPack {
-///         &mut self.0.backpack
-///     }
 ///  }
 /// ```
 
-#[derive(PartialEq, Eq, Hash, Clone)]
+#[derive(PartialEq, Clone)]
 pub enum Event {
     /// Robot has been initialized and its lifecycle has started
     Ready,
@@ -63,6 +42,9 @@ pub enum Event {
     /// [Event] fired when the day changes, contains the new [EnvironmentalConditions]
     DayChanged(EnvironmentalConditions),
 
+    /// [Event] fired after `DayChanged` when the weather of the new day is different, contains the new [WeatherType]
+    WeatherChanged(WeatherType),
+
     /// [Event] fired when energy gets recharged, contains the recharge amount
     EnergyRecharged(usize),
 
@@ -79,6 +61,43 @@ pub enum Event {
     /// This [Event] contains the [Tile] of the updated content and the coordinates
     TileContentUpdated(Tile, (usize, usize)),
 
+    /// [Event] fired when the robot uses a teleport
+    ///
+    /// This [Event] contains the [Tile] to which the robot was teleported and the coordinates
+    Teleported(Tile, (usize, usize)),
+
+    /// [Event] fired when `discover_tiles` succeeds, contains the coordinates of the tiles inside the map
+    TilesDiscovered(Vec<(usize, usize)>),
+
+    /// [Event] fired when a [Content] is crafted, contains the crafted [Content]
+    Crafted(Content),
+
+    /// [Event] fired when a [Content] is sold to a Market
+    ///
+    /// This [Event] contains the [Content] sold, the quantity sold and the coins received
+    Sold {
+        content: Content,
+        quantity: usize,
+        coins: usize,
+    },
+
+    /// [Event] fired when a [Content] is put into a Bank, a Bin or a Crate
+    ///
+    /// This [Event] contains the [Content] put, the quantity put and the `container` it was put into
+    Deposited {
+        content: Content,
+        quantity: usize,
+        container: Content,
+    },
+
+    /// [Event] fired when the score of the world increases because of the robot
+    ///
+    /// This [Event] contains the score added and the [ScoreReason]
+    ScoreIncreased {
+        delta: f32,
+        reason: ScoreReason,
+    },
+
     /// [Event] fired when a [Content] is added to the backpack, also contains the amount of content added
     AddedToBackpack(Content, usize),
 
@@ -93,6 +112,7 @@ impl Display for Event {
             | Event::Terminated => write!(f, "Robot has been terminated!"),
             | Event::TimeChanged(env) => write!(f, "Time changed, new environmental conditions: {:?}", env),
             | Event::DayChanged(env) => write!(f, "Day changed, new environmental conditions: {:?}", env),
+            | Event::WeatherChanged(weather) => write!(f, "Weather changed to {:?}", weather),
             | Event::EnergyRecharged(recharge) => write!(f, "Recharged with {} energy", recharge),
             | Event::EnergyConsumed(consumed) => write!(f, "Consumed {} energy", consumed),
             | Event::Moved(tile, (row, col)) => {
@@ -101,6 +121,22 @@ impl Display for Event {
             | Event::TileContentUpdated(tile, (row, col)) => {
                 write!(f, "Tile content updated at coords ({}, {}) to {:?}", row, col, tile)
             }
+            | Event::Teleported(tile, (row, col)) => {
+                write!(f, "Teleported to coordinates ({}, {}) with tile {:?}", row, col, tile)
+            }
+            | Event::TilesDiscovered(coordinates) => write!(f, "Discovered tiles {:?}", coordinates),
+            | Event::Crafted(content) => write!(f, "Crafted {:?}", content),
+            | Event::Sold {
+                content,
+                quantity,
+                coins,
+            } => write!(f, "Sold {} amount of {:?} for {} coins", quantity, content, coins),
+            | Event::Deposited {
+                content,
+                quantity,
+                container,
+            } => write!(f, "Deposited {} amount of {:?} into {:?}", quantity, content, container),
+            | Event::ScoreIncreased { delta, reason } => write!(f, "Score increased by {} ({})", delta, reason),
             | Event::AddedToBackpack(content, amount) => {
                 write!(f, "Added {} amount of {:?} to backpack", amount, content)
             }
@@ -111,8 +147,78 @@ impl Display for Event {
     }
 }
 
+// Eq and Hash can't be derived because of the f32 score delta, which is hashed by its bits.
+// A score is never NaN, so the derived PartialEq is a full equivalence
+impl Eq for Event {}
+
+impl Hash for Event {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        mem::discriminant(self).hash(state);
+        match self {
+            | Event::Ready | Event::Terminated => {}
+            | Event::TimeChanged(env) | Event::DayChanged(env) => env.hash(state),
+            | Event::WeatherChanged(weather) => weather.hash(state),
+            | Event::EnergyRecharged(amount) | Event::EnergyConsumed(amount) => amount.hash(state),
+            | Event::Moved(tile, coordinates)
+            | Event::TileContentUpdated(tile, coordinates)
+            | Event::Teleported(tile, coordinates) => {
+                tile.hash(state);
+                coordinates.hash(state);
+            }
+            | Event::TilesDiscovered(coordinates) => coordinates.hash(state),
+            | Event::Crafted(content) => content.hash(state),
+            | Event::AddedToBackpack(content, amount) | Event::RemovedFromBackpack(content, amount) => {
+                content.hash(state);
+                amount.hash(state);
+            }
+            | Event::Sold {
+                content,
+                quantity,
+                coins,
+            } => {
+                content.hash(state);
+                quantity.hash(state);
+                coins.hash(state);
+            }
+            | Event::Deposited {
+                content,
+                quantity,
+                container,
+            } => {
+                content.hash(state);
+                quantity.hash(state);
+                container.hash(state);
+            }
+            | Event::ScoreIncreased { delta, reason } => {
+                delta.to_bits().hash(state);
+                reason.hash(state);
+            }
+        }
+    }
+}
+
 impl fmt::Debug for Event {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
         write!(f, "Event: {}", self)
     }
 }
+
+/// Why the score increased, carried by `Event::ScoreIncreased`
+///
+/// # Variants
+/// - `Destroyed(Content)`: The robot destroyed this [Content] and collected it
+/// - `Disposed(Content)`: The robot put something into this [Content] (a Bank, a Bin, a Crate or a Fire)
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+pub enum ScoreReason {
+    Destroyed(Content),
+    Disposed(Content),
+}
+
+impl Display for ScoreReason {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            | ScoreReason::Destroyed(content) => write!(f, "destroyed {:?}", content),
+            | ScoreReason::Disposed(content) => write!(f, "disposed into {:?}", content),
+        }
+    }
+}
@@ -1 +1,42 @@
+use std::cell::RefCell;
+
+use crate::event::events::Event;
+use crate::runner::Runnable;
+
 pub mod events;
+
+thread_local! {
+    // The events fired during the turn of a robot, only recorded while the Runner has observers
+    static RECORDED: RefCell<Option<Vec<Event>>> = const { RefCell::new(None) };
+}
+
+/// Gives the event to the robot, and records it for the observers of the `Runner`
+pub(crate) fn fire(robot: &mut impl Runnable, event: Event) {
+    RECORDED.with(|recorded| {
+        if let Some(events) = recorded.borrow_mut().as_mut() {
+            events.push(event.clone());
+        }
+    });
+    robot.handle_event(event);
+}
+
+/// Starts recording the events fired on this thread
+pub(crate) fn start_recording() {
+    RECORDED.with(|recorded| *recorded.borrow_mut() = Some(vec![]));
+}
+
+/// Stops recording the events fired on this thread
+///
+/// # Returns
+/// The events fired since `start_recording`
+pub(crate) fn stop_recording() -> Vec<Event> {
+    RECORDED.with(|recorded| recorded.borrow_mut().take().unwrap_or_default())
+}
+
+/// Runs `f` without recording the events it fires, like the ones of the copies used by the estimates
+pub(crate) fn without_recording<T>(f: impl FnOnce() -> T) -> T {
+    let paused = RECORDED.with(|recorded| recorded.borrow_mut().take());
+    let result = f();
+    RECORDED.with(|recorded| *recorded.borrow_mut() = paused);
+    result
+}
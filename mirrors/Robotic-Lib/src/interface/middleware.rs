@@ -0,0 +1,399 @@
+use std::collections::HashMap;
+use std::fmt;
+use std::fmt::{Display, Formatter};
+use std::sync::{Arc, Mutex};
+
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+use crate::interface::{craft, destroy, discover_tiles, go, put, teleport, Direction, TileMatrix};
+use crate::runner::{MaybeSend, Runnable};
+use crate::utils::LibError;
+use crate::world::tile::{Content, Tile};
+use crate::world::World;
+
+/// An interface call made through an `InterfaceContext`, with its arguments.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Call {
+    Go(Direction),
+    Teleport((usize, usize)),
+    Destroy(Direction),
+    Put {
+        content: Content,
+        quantity: usize,
+        direction: Direction,
+    },
+    Craft(Content),
+    DiscoverTiles(Vec<(usize, usize)>),
+}
+
+impl Call {
+    /// The name of the interface function, like `"go"`
+    pub fn name(&self) -> &'static str {
+        match self {
+            | Call::Go(_) => "go",
+            | Call::Teleport(_) => "teleport",
+            | Call::Destroy(_) => "destroy",
+            | Call::Put { .. } => "put",
+            | Call::Craft(_) => "craft",
+            | Call::DiscoverTiles(_) => "discover_tiles",
+        }
+    }
+}
+
+impl Display for Call {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            | Call::Go(direction) | Call::Destroy(direction) => write!(f, "{}({:?})", self.name(), direction),
+            | Call::Teleport((row, col)) => write!(f, "{}({}, {})", self.name(), row, col),
+            | Call::Put {
+                content,
+                quantity,
+                direction,
+            } => write!(f, "{}({}, {}, {:?})", self.name(), content, quantity, direction),
+            | Call::Craft(content) => write!(f, "{}({})", self.name(), content),
+            | Call::DiscoverTiles(tiles) => write!(f, "{}({:?})", self.name(), tiles),
+        }
+    }
+}
+
+/// A finished call, given to `Middleware::after`.
+///
+/// # Fields
+/// - `call`: The call and its arguments
+/// - `result`: `Ok` if the call succeeded, otherwise its error, which can come from a middleware
+/// - `energy_spent`: The energy consumed by the call
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CallRecord {
+    pub call: Call,
+    pub result: Result<(), LibError>,
+    pub energy_spent: usize,
+}
+
+impl Display for CallRecord {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self.result {
+            | Ok(()) => write!(f, "{}: ok, {} energy", self.call, self.energy_spent),
+            | Err(error) => write!(f, "{}: {:?}, {} energy", self.call, error, self.energy_spent),
+        }
+    }
+}
+
+/// Sits between a robot and the interface, in an `InterfaceContext`.
+///
+/// `before` is called before the call reaches the interface, in the order the middleware was added: returning an
+/// error refuses the call, which then returns that error without touching the robot or the world and without
+/// asking the middleware that comes next. `after` is called on every middleware once the call is over, refused
+/// calls included. Both methods do nothing by default.
+///
+/// # Example
+/// ```rust
+/// use robotics_lib::interface::middleware::{CallRecord, Middleware};
+///
+/// struct Printer;
+///
+/// impl Middleware for Printer {
+///     fn after(&mut self, record: &CallRecord) {
+///         println!("{}", record);
+///     }
+/// }
+/// ```
+pub trait Middleware: MaybeSend {
+    fn before(&mut self, _call: &Call) -> Result<(), LibError> {
+        Ok(())
+    }
+    fn after(&mut self, _record: &CallRecord) {}
+}
+
+/// The interface actions, called through a chain of `Middleware`.
+///
+/// It has the same functions as the interface, with the same arguments and results. A robot that keeps its context
+/// in a field can't give both to a call, as the robot is borrowed too: the context is taken out of the robot while
+/// it's used and put back afterwards, `Default` leaving an empty one in its place.
+///
+/// # Example
+/// ```rust
+/// use robotics_lib::event::events::Event;
+/// use robotics_lib::interface::middleware::{Call, CallLog, InterfaceContext, Policy};
+/// use robotics_lib::interface::Direction;
+/// use robotics_lib::runner::{Robot, RobotState, Runnable, Runner};
+/// use robotics_lib::testing::ascii_world::AsciiWorld;
+/// use robotics_lib::world::World;
+///
+/// #[derive(RobotState)]
+/// struct MyRobot {
+///     #[robot]
+///     robot: Robot,
+///     interface: InterfaceContext,
+/// }
+///
+/// impl Runnable for MyRobot {
+///     fn process_tick(&mut self, world: &mut World) {
+///         let mut interface = std::mem::take(&mut self.interface);
+///         let _ = interface.go(self, world, Direction::Right);
+///         self.interface = interface;
+///     }
+///     fn handle_event(&mut self, _event: Event) {}
+/// }
+///
+/// let log = CallLog::new();
+/// let interface = InterfaceContext::new()
+///     .with(Policy::new(|call| !matches!(call, Call::Teleport(_))))
+///     .with(log.clone());
+/// let robot = MyRobot { robot: Robot::new(), interface };
+///
+/// let mut world = AsciiWorld::parse("G@ G\nG G").unwrap();
+/// let mut runner = Runner::new(Box::new(robot), &mut world).unwrap();
+/// runner.game_tick().unwrap();
+/// assert_eq!(log.get_records()[0].call, Call::Go(Direction::Right));
+/// ```
+#[derive(Default)]
+pub struct InterfaceContext {
+    middleware: Vec<Box<dyn Middleware>>,
+}
+
+impl InterfaceContext {
+    /// Creates a context without middleware, which calls the interface directly
+    pub fn new() -> Self {
+        InterfaceContext { middleware: vec![] }
+    }
+
+    /// Adds a middleware at the end of the chain
+    pub fn with(mut self, middleware: impl Middleware + 'static) -> Self {
+        self.middleware.push(Box::new(middleware));
+        self
+    }
+
+    /// Runs the call through the middleware, `action` is the interface function
+    fn call<R: Runnable, T>(
+        &mut self,
+        robot: &mut R,
+        call: Call,
+        action: impl FnOnce(&mut R) -> Result<T, LibError>,
+    ) -> Result<T, LibError> {
+        let energy = robot.get_energy().get_energy_level();
+        let result = match self
+            .middleware
+            .iter_mut()
+            .try_for_each(|middleware| middleware.before(&call))
+        {
+            | Ok(()) => action(robot),
+            | Err(error) => Err(error),
+        };
+
+        let record = CallRecord {
+            call,
+            result: result.as_ref().map(|_| ()).map_err(|error| *error),
+            energy_spent: energy.saturating_sub(robot.get_energy().get_energy_level()),
+        };
+        for middleware in self.middleware.iter_mut() {
+            middleware.after(&record);
+        }
+        result
+    }
+
+    /// Calls `interface::go`
+    pub fn go(
+        &mut self,
+        robot: &mut impl Runnable,
+        world: &mut World,
+        direction: Direction,
+    ) -> Result<TileMatrix, LibError> {
+        self.call(robot, Call::Go(direction.clone()), |robot| go(robot, world, direction))
+    }
+
+    /// Calls `interface::teleport`
+    pub fn teleport(
+        &mut self,
+        robot: &mut impl Runnable,
+        world: &mut World,
+        coordinates: (usize, usize),
+    ) -> Result<TileMatrix, LibError> {
+        self.call(robot, Call::Teleport(coordinates), |robot| {
+            teleport(robot, world, coordinates)
+        })
+    }
+
+    /// Calls `interface::destroy`
+    pub fn destroy(
+        &mut self,
+        robot: &mut impl Runnable,
+        world: &mut World,
+        direction: Direction,
+    ) -> Result<usize, LibError> {
+        self.call(robot, Call::Destroy(direction.clone()), |robot| {
+            destroy(robot, world, direction)
+        })
+    }
+
+    /// Calls `interface::put`
+    pub fn put(
+        &mut self,
+        robot: &mut impl Runnable,
+        world: &mut World,
+        content: Content,
+        quantity: usize,
+        direction: Direction,
+    ) -> Result<usize, LibError> {
+        let call = Call::Put {
+            content: content.clone(),
+            quantity,
+            direction: direction.clone(),
+        };
+        self.call(robot, call, |robot| put(robot, world, content, quantity, direction))
+    }
+
+    /// Calls `interface::craft`
+    pub fn craft(&mut self, robot: &mut impl Runnable, content: Content) -> Result<Content, LibError> {
+        self.call(robot, Call::Craft(content.clone()), |robot| craft(robot, content))
+    }
+
+    /// Calls `interface::discover_tiles`
+    pub fn discover_tiles(
+        &mut self,
+        robot: &mut impl Runnable,
+        world: &mut World,
+        to_discover: &[(usize, usize)],
+    ) -> Result<HashMap<(usize, usize), Option<Tile>>, LibError> {
+        self.call(robot, Call::DiscoverTiles(to_discover.to_vec()), |robot| {
+            discover_tiles(robot, world, to_discover)
+        })
+    }
+}
+
+/// The statistics of the calls to an interface function, given by `CallLog::get_stats`.
+///
+/// # Fields
+/// - `calls`: The number of calls
+/// - `failures`: The number of calls that returned an error
+/// - `energy_spent`: The energy consumed by all the calls
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct CallStats {
+    pub calls: usize,
+    pub failures: usize,
+    pub energy_spent: usize,
+}
+
+/// A middleware that keeps a record of every call.
+///
+/// Its clones share the same records, so a clone can be kept to read them while the original is in an
+/// `InterfaceContext`.
+#[derive(Clone, Default)]
+pub struct CallLog {
+    records: Arc<Mutex<Vec<CallRecord>>>,
+}
+
+impl CallLog {
+    pub fn new() -> Self {
+        CallLog::default()
+    }
+
+    /// The records of the calls, oldest first
+    pub fn get_records(&self) -> Vec<CallRecord> {
+        self.records.lock().unwrap().clone()
+    }
+
+    /// The statistics of every interface function that was called, by name
+    pub fn get_stats(&self) -> HashMap<&'static str, CallStats> {
+        let mut stats: HashMap<&'static str, CallStats> = HashMap::new();
+        for record in self.records.lock().unwrap().iter() {
+            let entry = stats.entry(record.call.name()).or_default();
+            entry.calls += 1;
+            entry.failures += record.result.is_err() as usize;
+            entry.energy_spent += record.energy_spent;
+        }
+        stats
+    }
+}
+
+impl Middleware for CallLog {
+    fn after(&mut self, record: &CallRecord) {
+        self.records.lock().unwrap().push(record.clone());
+    }
+}
+
+#[cfg(feature = "send")]
+type Rule = Box<dyn FnMut(&Call) -> bool + Send>;
+#[cfg(not(feature = "send"))]
+type Rule = Box<dyn FnMut(&Call) -> bool>;
+
+/// A middleware that refuses the calls its rule doesn't allow, with `OperationNotAllowed`.
+///
+/// The rule is called once for every call that reaches the policy and can keep a state, like a count of the calls.
+///
+/// # Example
+/// ```rust
+/// use robotics_lib::interface::middleware::{Call, Policy};
+///
+/// // no put of more than 2 items at a time
+/// let small_puts = Policy::new(|call| match call {
+///     Call::Put { quantity, .. } => *quantity <= 2,
+///     _ => true,
+/// });
+///
+/// // at most 3 teleports
+/// let mut teleports = 0;
+/// let few_teleports = Policy::new(move |call| {
+///     if matches!(call, Call::Teleport(_)) {
+///         teleports += 1;
+///     }
+///     teleports <= 3
+/// });
+/// ```
+pub struct Policy {
+    allow: Rule,
+}
+
+impl Policy {
+    pub fn new(allow: impl FnMut(&Call) -> bool + MaybeSend + 'static) -> Self {
+        Policy {
+            allow: Box::new(allow),
+        }
+    }
+}
+
+impl Middleware for Policy {
+    fn before(&mut self, call: &Call) -> Result<(), LibError> {
+        if (self.allow)(call) {
+            Ok(())
+        } else {
+            Err(LibError::OperationNotAllowed)
+        }
+    }
+}
+
+/// A middleware that makes calls fail at random, to test how a robot copes with errors.
+///
+/// Every call fails with `error` with the probability `rate`, before reaching the interface. The rate is clamped
+/// between 0.0 and 1.0, and NaN counts as 0.0.
+pub struct FaultInjection {
+    rate: f64,
+    error: LibError,
+    rng: StdRng,
+}
+
+impl FaultInjection {
+    pub fn new(rate: f64, error: LibError) -> Self {
+        FaultInjection {
+            rate: if rate.is_nan() { 0.0 } else { rate.clamp(0.0, 1.0) },
+            error,
+            rng: StdRng::from_entropy(),
+        }
+    }
+
+    /// Makes the failures the same in every run
+    pub fn with_seed(mut self, seed: u64) -> Self {
+        self.rng = StdRng::seed_from_u64(seed);
+        self
+    }
+}
+
+impl Middleware for FaultInjection {
+    fn before(&mut self, _call: &Call) -> Result<(), LibError> {
+        if self.rng.gen_bool(self.rate) {
+            Err(self.error)
+        } else {
+            Ok(())
+        }
+    }
+}
@@ -2,15 +2,21 @@ use std::cmp::min;
 use std::collections::HashMap;
 use std::fmt::Debug;
 use std::ops::Range;
-use std::sync::Mutex;
 
-use lazy_static::lazy_static;
 use rand::Rng;
 use strum_macros::EnumIter;
 
+use crate::energy::Energy;
+use crate::event::events::Event;
 use crate::event::events::Event::Moved;
-use crate::event::events::Event::{EnergyConsumed, TileContentUpdated};
-use crate::runner::Runnable;
+use crate::event::events::Event::{
+    Crafted, Deposited, EnergyConsumed, Sold, Teleported, TileContentUpdated, TilesDiscovered,
+};
+use crate::event::events::ScoreReason;
+use crate::event::{fire, without_recording};
+use crate::runner::backpack::BackPack;
+use crate::runner::{Robot, RobotState, Runnable};
+use crate::utils::pathfinding::go_cost;
 use crate::utils::LibError::*;
 use crate::utils::*;
 use crate::world::coordinates::Coordinate;
@@ -19,6 +25,8 @@ use crate::world::tile::TileType::{DeepWater, ShallowWater, Teleport};
 use crate::world::tile::{Content, Tile, TileType};
 use crate::world::World;
 
+pub mod middleware;
+
 /// Represents the Tools
 /// The `Tools` trait is used to define the Tools.
 ///
@@ -60,10 +68,8 @@ pub enum Direction {
     Right,
 }
 
-lazy_static! {
-/// List of coordinates that the robot has seen so far
-    static ref PLOT: Mutex<Vec<(usize, usize)>> = Mutex::new(vec![]);
-}
+/// The energy used by `teleport`
+pub(crate) const TELEPORT_COST: usize = 30;
 
 /// Represents the world made of ```rust Vec<Vec<Option<Tile>>> ``` and the coordinates of type ```rust (usize, usize)```
 type TileMatrix = (Vec<Vec<Option<Tile>>>, (usize, usize));
@@ -122,21 +128,8 @@ pub fn go(robot: &mut impl Runnable, world: &mut World, direction: Direction) ->
     let target_tile = &world.map[row][col];
     let current_tile = &world.map[robot.get_coordinate().get_row()][robot.get_coordinate().get_col()];
 
-    // Init costs
-    let mut base_cost = target_tile.tile_type.properties().cost();
-    let mut elevation_cost = 0;
-
-    // Get informations that influence the cost
-    let environmental_conditions = look_at_sky(world);
-    let new_elevation = target_tile.elevation;
-    let current_elevation = current_tile.elevation;
-
-    // Calculate cost
-    base_cost = calculate_cost_go_with_environment(base_cost, environmental_conditions, target_tile.tile_type);
-    // Consider elevation cost only if we are going from a lower tile to a higher tile
-    if new_elevation > current_elevation {
-        elevation_cost = (new_elevation - current_elevation).pow(2);
-    }
+    // Calculate cost, the same one used by the pathfinding
+    let cost = go_cost(current_tile, target_tile, &world.environmental_conditions);
 
     // Update teleport tile informations
     if world.map[row][col].tile_type == Teleport(false) {
@@ -144,12 +137,12 @@ pub fn go(robot: &mut impl Runnable, world: &mut World, direction: Direction) ->
     }
 
     // Consume energy and then move
-    robot.get_energy_mut().consume_energy(base_cost + elevation_cost)?;
+    robot.get_energy_mut().consume_energy(cost)?;
     *robot.get_coordinate_mut() = Coordinate::new(row, col);
 
     // Fire events
-    robot.handle_event(EnergyConsumed(base_cost + elevation_cost));
-    robot.handle_event(Moved(world.map[row][col].clone(), (row, col)));
+    fire(robot, EnergyConsumed(cost));
+    fire(robot, Moved(world.map[row][col].clone(), (row, col)));
     Ok(where_am_i(robot, world))
 }
 
@@ -178,13 +171,16 @@ pub fn teleport(
     world: &mut World,
     coordinates: (usize, usize),
 ) -> Result<TileMatrix, LibError> {
-    const TELEPORT_COST: usize = 30;
     let coordinate = Coordinate::new(coordinates.0, coordinates.1);
     match teleport_allowed(robot, world, &coordinate) {
         | Ok(_) => {
             robot.get_energy_mut().consume_energy(TELEPORT_COST)?;
-            robot.handle_event(EnergyConsumed(TELEPORT_COST));
+            fire(robot, EnergyConsumed(TELEPORT_COST));
             *robot.get_coordinate_mut() = coordinate;
+            fire(
+                robot,
+                Teleported(world.map[coordinates.0][coordinates.1].clone(), coordinates),
+            );
             Ok(where_am_i(robot, world))
         }
         | Err(e) => Err(e),
@@ -271,16 +267,17 @@ pub fn destroy(robot: &mut impl Runnable, world: &mut World, direction: Directio
 
     if robot.get_energy().has_enough_energy(cost) {
         let amt = add_to_backpack(robot, content.to_default(), value)?;
-        world.score_counter.add_score_destroy(&content.to_default(), amt);
+        let score = world.score_counter.add_score_destroy(&content.to_default(), amt);
 
         robot.get_energy_mut().consume_energy(cost)?;
-        robot.handle_event(EnergyConsumed(cost));
+        fire(robot, EnergyConsumed(cost));
+        score_increased(robot, score, ScoreReason::Destroyed(content.to_default()));
 
         world.map[target_row][target_col].content = Content::None;
-        robot.handle_event(TileContentUpdated(
-            world.map[target_row][target_col].clone(),
-            (target_row, target_col),
-        ));
+        fire(
+            robot,
+            TileContentUpdated(world.map[target_row][target_col].clone(), (target_row, target_col)),
+        );
 
         Ok(amt)
     } else {
@@ -309,17 +306,15 @@ pub fn destroy(robot: &mut impl Runnable, world: &mut World, direction: Directio
 /// # Usage
 ///
 ///```rust
-/// use robotics_lib::energy::Energy;
 /// use robotics_lib::event::events::Event;
 /// use robotics_lib::interface::{Direction, put};
-/// use robotics_lib::runner::{Robot, Runnable};
+/// use robotics_lib::runner::{Robot, RobotState, Runnable};
 /// use robotics_lib::world::environmental_conditions::EnvironmentalConditions;
 /// use robotics_lib::world::tile::{Content, Tile};
 /// use robotics_lib::world::World;
 /// use robotics_lib::world::world_generator::Generator;
-/// use robotics_lib::world::coordinates::Coordinate;
-/// use robotics_lib::runner::backpack::BackPack;
 ///
+/// #[derive(RobotState)]
 /// struct MyRobot(Robot);
 /// let mut  robot = MyRobot(Robot::new());
 /// impl Runnable for MyRobot { //dummy implementation
@@ -327,13 +322,6 @@ pub fn destroy(robot: &mut impl Runnable, world: &mut World, direction: Directio
 ///        let result = put(self, world, Content::Coin(0), 1, Direction::Up);
 ///    }
 ///    fn handle_event(&mut self, event: Event) {}
-///    fn get_energy(&self) -> &Energy {&self.0.energy}
-///    fn get_energy_mut(&mut self) -> &mut Energy {&mut self.0.energy}
-///    fn get_coordinate(&self) -> &Coordinate {&self.0.coordinate}
-///    fn get_coordinate_mut(&mut self) -> &mut Coordinate {&mut self.0.coordinate}
-///    fn get_backpack(&self) -> &BackPack {&self.0.backpack}
-///    fn get_backpack_mut(&mut self) -> &mut BackPack {&mut self.0.backpack}
-///
 /// }
 /// ```
 ///
@@ -449,10 +437,19 @@ pub fn put(
             let removed_quantity = remove_from_backpack(robot, &content_in.to_default(), quantity_to_remove)?;
             world.map[target_row][target_col].content = Content::Bank((range.start + removed_quantity)..range.end);
             robot.get_energy_mut().consume_energy(cost)?;
-            robot.handle_event(EnergyConsumed(cost));
-            world
+            fire(robot, EnergyConsumed(cost));
+            fire(
+                robot,
+                Deposited {
+                    content: content_in.to_default(),
+                    quantity: removed_quantity,
+                    container: Content::Bank(Range::default()),
+                },
+            );
+            let score = world
                 .score_counter
                 .add_score_put(&Content::Bank(Range::default()), removed_quantity); // Adds score
+            score_increased(robot, score, ScoreReason::Disposed(Content::Bank(Range::default())));
             Ok(removed_quantity)
         }
         | (_, Content::Bin(range), Content::Garbage(_)) => {
@@ -466,10 +463,19 @@ pub fn put(
             let removed_quantity = remove_from_backpack(robot, &content_in.to_default(), quantity_to_remove)?;
             world.map[target_row][target_col].content = Content::Bin((range.start + removed_quantity)..range.end);
             robot.get_energy_mut().consume_energy(cost)?;
-            robot.handle_event(EnergyConsumed(cost));
-            world
+            fire(robot, EnergyConsumed(cost));
+            fire(
+                robot,
+                Deposited {
+                    content: content_in.to_default(),
+                    quantity: removed_quantity,
+                    container: Content::Bin(Range::default()),
+                },
+            );
+            let score = world
                 .score_counter
                 .add_score_put(&Content::Bin(Range::default()), removed_quantity); // Adds score
+            score_increased(robot, score, ScoreReason::Disposed(Content::Bin(Range::default())));
             Ok(removed_quantity)
         }
         | (_, Content::Crate(range), Content::Tree(_)) => {
@@ -483,10 +489,19 @@ pub fn put(
             let removed_quantity = remove_from_backpack(robot, &content_in.to_default(), quantity_to_remove)?;
             world.map[target_row][target_col].content = Content::Crate((range.start + removed_quantity)..range.end);
             robot.get_energy_mut().consume_energy(cost)?;
-            robot.handle_event(EnergyConsumed(cost));
-            world
+            fire(robot, EnergyConsumed(cost));
+            fire(
+                robot,
+                Deposited {
+                    content: content_in.to_default(),
+                    quantity: removed_quantity,
+                    container: Content::Crate(Range::default()),
+                },
+            );
+            let score = world
                 .score_counter
                 .add_score_put(&Content::Crate(Range::default()), removed_quantity); // Adds score
+            score_increased(robot, score, ScoreReason::Disposed(Content::Crate(Range::default())));
             Ok(removed_quantity)
         }
         | (_, Content::Tree(_), Content::Fire) | (_, Content::None, Content::Fire) => {
@@ -503,7 +518,7 @@ pub fn put(
             } else {
                 let removed_quantity = remove_from_backpack(robot, &input.2.to_default(), 1)?;
                 robot.get_energy_mut().consume_energy(cost)?;
-                robot.handle_event(EnergyConsumed(cost));
+                fire(robot, EnergyConsumed(cost));
                 world.map[target_row][target_col].content = input.2.to_default();
                 Ok(removed_quantity)
             }
@@ -525,7 +540,16 @@ pub fn put(
                 return Err(WrongContentUsed);
             }
 
-            Ok(add_to_backpack(robot, Content::Coin(0), items_sold * coins)?)
+            let coins = add_to_backpack(robot, Content::Coin(0), items_sold * coins)?;
+            fire(
+                robot,
+                Sold {
+                    content: to_sell.to_default(),
+                    quantity: items_sold,
+                    coins,
+                },
+            );
+            Ok(coins)
         }
         | (TileType::Grass | TileType::Hill | TileType::Sand | TileType::Snow, _, Content::Rock(_)) => {
             // cost = content_cost (rock_cost) * amount (1) * inherit multiplier (1)
@@ -538,7 +562,7 @@ pub fn put(
             } else {
                 let removed_quantity = remove_from_backpack(robot, &input.2.to_default(), 1)?;
                 robot.get_energy_mut().consume_energy(cost)?;
-                robot.handle_event(EnergyConsumed(cost));
+                fire(robot, EnergyConsumed(cost));
                 world.map[target_row][target_col].tile_type = TileType::Street;
                 Ok(removed_quantity)
             }
@@ -558,7 +582,7 @@ pub fn put(
             } else {
                 let removed_quantity = remove_from_backpack(robot, &input.2.to_default(), 2)?;
                 robot.get_energy_mut().consume_energy(cost)?;
-                robot.handle_event(EnergyConsumed(cost));
+                fire(robot, EnergyConsumed(cost));
                 world.map[target_row][target_col].tile_type = TileType::Street;
                 Ok(removed_quantity)
             }
@@ -578,7 +602,7 @@ pub fn put(
             } else {
                 let removed_quantity = remove_from_backpack(robot, &input.2.to_default(), 3)?;
                 robot.get_energy_mut().consume_energy(cost)?;
-                robot.handle_event(EnergyConsumed(cost));
+                fire(robot, EnergyConsumed(cost));
                 world.map[target_row][target_col].tile_type = TileType::Street;
                 Ok(removed_quantity)
             }
@@ -595,7 +619,7 @@ pub fn put(
             } else {
                 let removed_quantity = remove_from_backpack(robot, &input.2.to_default(), 3)?;
                 robot.get_energy_mut().consume_energy(cost)?;
-                robot.handle_event(EnergyConsumed(cost));
+                fire(robot, EnergyConsumed(cost));
                 world.map[target_row][target_col].tile_type = TileType::Street;
                 Ok(removed_quantity)
             }
@@ -610,7 +634,7 @@ pub fn put(
             } else {
                 let removed_quantity = remove_from_backpack(robot, &input.2.to_default(), amount)?;
                 robot.get_energy_mut().consume_energy(cost)?;
-                robot.handle_event(EnergyConsumed(cost));
+                fire(robot, EnergyConsumed(cost));
                 world.map[target_row][target_col].content = Content::Rock(amount);
                 Ok(removed_quantity)
             }
@@ -625,7 +649,7 @@ pub fn put(
             } else {
                 let added_quantity = add_to_backpack(robot, Content::Rock(0), amount_to_give)?;
                 robot.get_energy_mut().consume_energy(cost)?;
-                robot.handle_event(EnergyConsumed(cost));
+                fire(robot, EnergyConsumed(cost));
                 world.map[target_row][target_col].tile_type = TileType::Street;
                 Ok(added_quantity)
             }
@@ -637,9 +661,10 @@ pub fn put(
             } else {
                 let removed_quantity = remove_from_backpack(robot, &input.2.to_default(), 1)?;
                 robot.get_energy_mut().consume_energy(cost)?;
-                robot.handle_event(EnergyConsumed(cost));
+                fire(robot, EnergyConsumed(cost));
                 world.map[target_row][target_col].content = Content::None;
-                world.score_counter.add_score_put(&Content::Fire, removed_quantity); // Adds score
+                let score = world.score_counter.add_score_put(&Content::Fire, removed_quantity); // Adds score
+                score_increased(robot, score, ScoreReason::Disposed(Content::Fire));
                 Ok(removed_quantity)
             }
         }
@@ -650,7 +675,7 @@ pub fn put(
             } else {
                 let removed_quantity = remove_from_backpack(robot, &input.2.to_default(), amount)?;
                 robot.get_energy_mut().consume_energy(cost)?;
-                robot.handle_event(EnergyConsumed(cost));
+                fire(robot, EnergyConsumed(cost));
                 Ok(removed_quantity)
             }
         }
@@ -664,7 +689,7 @@ pub fn put(
             } else {
                 let removed_quantity = remove_from_backpack(robot, &input.2.to_default(), amount)?;
                 robot.get_energy_mut().consume_energy(cost)?;
-                robot.handle_event(EnergyConsumed(cost));
+                fire(robot, EnergyConsumed(cost));
                 world.map[target_row][target_col].content = input.2.to_value(amount);
                 Ok(removed_quantity)
             }
@@ -687,7 +712,7 @@ pub fn put(
             } else {
                 let removed_quantity = remove_from_backpack(robot, &input.2.to_default(), amount)?;
                 robot.get_energy_mut().consume_energy(cost)?;
-                robot.handle_event(EnergyConsumed(cost));
+                fire(robot, EnergyConsumed(cost));
                 world.map[target_row][target_col].content = input.2.to_value(amount + value.unwrap());
                 Ok(removed_quantity)
             }
@@ -695,10 +720,10 @@ pub fn put(
     };
 
     if put_result.is_ok() {
-        robot.handle_event(TileContentUpdated(
-            world.map[target_row][target_col].clone(),
-            (target_row, target_col),
-        ))
+        fire(
+            robot,
+            TileContentUpdated(world.map[target_row][target_col].clone(), (target_row, target_col)),
+        )
     }
 
     put_result
@@ -769,15 +794,8 @@ pub fn debug(robot: &impl Runnable, world: &mut World) -> (Vec<Vec<Tile>>, usize
 /// - The map of the robot is returned
 /// - The map of the robot is a matrix of `Option<Tile>`
 pub fn robot_map(world: &World) -> Option<Vec<Vec<Option<Tile>>>> {
-    let mut out: Vec<Vec<Option<Tile>>> = vec![vec![None; world.dimension]; world.dimension];
-    if let Ok(plot_guard) = PLOT.lock() {
-        for (x, y) in plot_guard.iter() {
-            out[*x][*y] = Some(world.map[*x][*y].clone());
-        }
-        Some(out)
-    } else {
-        None
-    }
+    // every robot only sees what it discovered in this world
+    Some(world.discovered_map(world.robots.active))
 }
 
 /// Given the world, will return the area around the robot
@@ -861,8 +879,8 @@ pub fn robot_view(robot: &impl Runnable, world: &World) -> Vec<Vec<Option<Tile>>
                 let col = robot_col + j - 1;
                 out[i][j] = Some(world.map[row][col].clone());
 
-                // add to plot
-                add_to_plot(&PLOT, row, col);
+                // mark as discovered
+                world.discover(row, col);
             }
         })
     });
@@ -941,12 +959,12 @@ pub fn one_direction_view(
                     let row = robot_row - i;
                     let col = (robot_col as isize + j) as usize;
                     row_vec.push(world.map[row][col].clone());
-                    add_to_plot(&PLOT, row, col);
+                    world.discover(row, col);
                 }
                 out.push(row_vec);
             }
             robot.get_energy_mut().consume_energy(energy_needed)?;
-            robot.handle_event(EnergyConsumed(energy_needed));
+            fire(robot, EnergyConsumed(energy_needed));
             Ok(out)
         }
         | Direction::Down => {
@@ -963,12 +981,12 @@ pub fn one_direction_view(
                     let row = robot_row + i;
                     let col = (robot_col as isize + j) as usize;
                     row_vec.push(world.map[row][col].clone());
-                    add_to_plot(&PLOT, row, col);
+                    world.discover(row, col);
                 }
                 out.push(row_vec);
             }
             robot.get_energy_mut().consume_energy(energy_needed)?;
-            robot.handle_event(EnergyConsumed(energy_needed));
+            fire(robot, EnergyConsumed(energy_needed));
             Ok(out)
         }
         | Direction::Left => {
@@ -985,12 +1003,12 @@ pub fn one_direction_view(
                     let row = (robot_row as isize + i) as usize;
                     let col = robot_col - j;
                     row_vec.push(world.map[row][col].clone());
-                    add_to_plot(&PLOT, row, col);
+                    world.discover(row, col);
                 }
                 out.push(row_vec);
             }
             robot.get_energy_mut().consume_energy(energy_needed)?;
-            robot.handle_event(EnergyConsumed(energy_needed));
+            fire(robot, EnergyConsumed(energy_needed));
             Ok(out)
         }
         | Direction::Right => {
@@ -1007,12 +1025,12 @@ pub fn one_direction_view(
                     let row = (robot_row as isize + i) as usize;
                     let col = robot_col + j;
                     row_vec.push(world.map[row][col].clone());
-                    add_to_plot(&PLOT, row, col);
+                    world.discover(row, col);
                 }
                 out.push(row_vec);
             }
             robot.get_energy_mut().consume_energy(energy_needed)?;
-            robot.handle_event(EnergyConsumed(energy_needed));
+            fire(robot, EnergyConsumed(energy_needed));
             Ok(out)
         }
     }
@@ -1069,9 +1087,9 @@ pub fn where_am_i(robot: &impl Runnable, world: &World) -> (Vec<Vec<Option<Tile>
 /// - `world`: Targeted world
 ///
 /// # Returns
-/// - `f32`: Received score
+/// - `f32`: Received score, when more robots share the world only the one made by the robot whose turn it is
 pub fn get_score(world: &World) -> f32 {
-    world.score_counter.get_score()
+    world.robot_score()
 }
 
 /// Given a content to craft, will attempt to craft it from the contents already present in the backpack
@@ -1129,9 +1147,10 @@ pub fn craft(robot: &mut impl Runnable, content: Content) -> Result<Content, Lib
                                 //make the robot pay
                                 let cost = content.properties().cost();
                                 robot.get_energy_mut().consume_energy(cost)?;
-                                robot.handle_event(EnergyConsumed(cost));
+                                fire(robot, EnergyConsumed(cost));
                                 // there was enough contents to craft
                                 add_to_backpack(robot, content.to_default(), 1)?;
+                                fire(robot, Crafted(content.to_default()));
                                 return Ok(content);
                             }
                         }
@@ -1185,16 +1204,19 @@ pub fn discover_tiles(
         if robot.get_energy().has_enough_energy(energy_needed) {
             world.discoverable -= to_discover.len();
             robot.get_energy_mut().consume_energy(energy_needed)?;
-            robot.handle_event(EnergyConsumed(energy_needed));
+            fire(robot, EnergyConsumed(energy_needed));
+            let mut discovered = Vec::with_capacity(to_discover.len());
             for (x, y) in to_discover.iter() {
                 if *x < world.map.len() && *y < world.map[*x].len() {
                     let tile = world.map[*x][*y].clone();
                     return_value.insert((*x, *y), Some(tile));
-                    add_to_plot(&PLOT, *x, *y);
+                    world.discover(*x, *y);
+                    discovered.push((*x, *y));
                 } else {
                     return_value.insert((*x, *y), None);
                 }
             }
+            fire(robot, TilesDiscovered(discovered));
         } else {
             return Err(NotEnoughEnergy);
         }
@@ -1203,3 +1225,286 @@ pub fn discover_tiles(
     }
     Ok(return_value)
 }
+
+/// Given the robot, the world and the direction, returns the energy `go` would use, without moving the robot
+///
+/// # Usage
+/// ```rust
+/// use robotics_lib::interface::estimate_go;
+/// ```
+///
+/// # Returns
+/// - `Ok`: The energy `go` would use
+/// - `Err`: The error `go` would return
+///
+/// # Errors
+/// The same as `go`
+pub fn estimate_go(robot: &impl Runnable, world: &World, direction: Direction) -> Result<usize, LibError> {
+    go_allowed(robot, world, &direction)?;
+
+    let (row, col) = get_coords_row_col(robot, &direction);
+    let current_tile = &world.map[robot.get_coordinate().get_row()][robot.get_coordinate().get_col()];
+    let cost = go_cost(current_tile, &world.map[row][col], &world.environmental_conditions);
+    enough_energy(robot, cost)
+}
+
+/// Given the robot, the world and the coordinates of a teleport tile, returns the energy `teleport` would use,
+/// without moving the robot
+///
+/// # Usage
+/// ```rust
+/// use robotics_lib::interface::estimate_teleport;
+/// ```
+///
+/// # Returns
+/// - `Ok`: The energy `teleport` would use
+/// - `Err`: The error `teleport` would return
+///
+/// # Errors
+/// The same as `teleport`
+pub fn estimate_teleport(robot: &impl Runnable, world: &World, coordinates: (usize, usize)) -> Result<usize, LibError> {
+    teleport_allowed(robot, world, &Coordinate::new(coordinates.0, coordinates.1))?;
+    enough_energy(robot, TELEPORT_COST)
+}
+
+/// Given the robot, the world and the direction, returns the energy `destroy` would use, without changing the
+/// world or the robot
+///
+/// Taking water from a lake gives a random amount of water, so its estimate doesn't check whether the backpack has
+/// room for it.
+///
+/// # Usage
+/// ```rust
+/// use robotics_lib::interface::estimate_destroy;
+/// ```
+///
+/// # Returns
+/// - `Ok`: The energy `destroy` would use
+/// - `Err`: The error `destroy` would return
+///
+/// # Errors
+/// The same as `destroy`
+pub fn estimate_destroy(robot: &impl Runnable, world: &World, direction: Direction) -> Result<usize, LibError> {
+    in_bounds(robot, world, &direction)?;
+    let (target_row, target_col) = get_coords_row_col(robot, &direction);
+    let tile = &world.map[target_row][target_col];
+    let water = [ShallowWater, DeepWater].contains(&tile.tile_type);
+
+    if water && tile.content == Content::None {
+        return enough_energy(robot, Content::Water(0).properties().cost());
+    }
+    if !water && !can_destroy(world, (target_row, target_col))? {
+        return Err(CannotDestroy);
+    }
+
+    let value = match tile.content {
+        | Content::Fire => Content::Fire.properties().max(),
+        | _ => tile.content.get_value().0.unwrap_or(0),
+    };
+    let cost = enough_energy(robot, tile.content.properties().cost())?;
+    let space = backpack_space(robot);
+    if space < value {
+        Err(NotEnoughSpace(space))
+    } else {
+        Ok(cost)
+    }
+}
+
+/// Given the robot, the world, the content, the quantity and the direction, returns the energy `put` would use,
+/// without changing the world or the robot
+///
+/// Digging a Mountain gives a random amount of rocks, and the energy it uses depends on it: its estimate is the
+/// energy needed for the most rocks it can give, so a robot that has that much can always dig. Whether the backpack
+/// has room for the rocks is not checked.
+///
+/// # Usage
+/// ```rust
+/// use robotics_lib::interface::estimate_put;
+/// ```
+///
+/// # Returns
+/// - `Ok`: The energy `put` would use
+/// - `Err`: The error `put` would return
+///
+/// # Errors
+/// The same as `put`
+pub fn estimate_put(
+    robot: &impl Runnable,
+    world: &World,
+    content_in: Content,
+    quantity: usize,
+    direction: Direction,
+) -> Result<usize, LibError> {
+    in_bounds(robot, world, &direction)?;
+    let (target_row, target_col) = get_coords_row_col(robot, &direction);
+    let tile = &world.map[target_row][target_col];
+    if content_in == Content::None && tile.tile_type != TileType::Mountain {
+        return Err(WrongContentUsed);
+    }
+
+    let held = *robot
+        .get_backpack()
+        .contents
+        .get(&content_in.to_default())
+        .unwrap_or(&0);
+    let amount = min(min(quantity, held), content_in.properties().max());
+    // `put` pays once it has taken the content out of the backpack, which fails if there is none
+    let take = |cost: usize| if held == 0 { Err(NoContent) } else { Ok(cost) };
+    let rock_on = |cost: usize, needed: usize| {
+        if amount < needed {
+            Err(NotEnoughContentProvided)
+        } else {
+            enough_energy(robot, cost).and_then(take)
+        }
+    };
+    let street_can_hold = || {
+        if TileType::Street.properties().can_hold(&tile.content) {
+            Ok(())
+        } else {
+            Err(MustDestroyContentFirst)
+        }
+    };
+    let can_hold = || {
+        if tile.tile_type.properties().can_hold(&content_in) {
+            Ok(())
+        } else {
+            Err(WrongContentUsed)
+        }
+    };
+
+    // the arms follow the ones of `put`, estimate_put_branches_test plays `put` after the estimate for each of them
+    match (&tile.tile_type, &tile.content, &content_in) {
+        | (_, Content::Bank(range), Content::Coin(_))
+        | (_, Content::Bin(range), Content::Garbage(_))
+        | (_, Content::Crate(range), Content::Tree(_)) => {
+            let stored = min(min(range.end - range.start, held), quantity);
+            enough_energy(robot, tile.content.properties().cost() * stored).and_then(take)
+        }
+        | (_, Content::Tree(_), Content::Fire) | (_, Content::None, Content::Fire) => {
+            can_hold()?;
+            enough_energy(robot, content_in.properties().cost()).and_then(take)
+        }
+        | (_, Content::Market(remaining_op), to_sell) => {
+            if *remaining_op < 1 {
+                return Err(OperationNotAllowed);
+            }
+            take(0)?;
+            let coins = match to_sell {
+                | Content::Rock(_) => 1,
+                | Content::Tree(_) => 2,
+                | Content::Fish(_) => 5,
+                | _ => return Err(WrongContentUsed),
+            };
+            // the items sold leave the backpack before the coins come in
+            let sold = min(quantity, held);
+            let space = backpack_space(robot) + sold;
+            if space < sold * coins {
+                Err(NotEnoughSpace(space))
+            } else {
+                Ok(0)
+            }
+        }
+        | (TileType::Grass | TileType::Hill | TileType::Sand | TileType::Snow, _, Content::Rock(_)) => {
+            street_can_hold()?;
+            enough_energy(robot, content_in.properties().cost()).and_then(take)
+        }
+        | (TileType::ShallowWater, _, Content::Rock(_)) => {
+            street_can_hold()?;
+            rock_on(content_in.properties().cost() * 2, 2)
+        }
+        | (TileType::DeepWater, _, Content::Rock(_)) => {
+            street_can_hold()?;
+            rock_on(content_in.properties().cost() * 3 * 2, 3)
+        }
+        | (TileType::Lava, _, Content::Rock(_)) => rock_on(content_in.properties().cost() * 3 * 3, 3),
+        | (_, Content::None, Content::Rock(_)) => {
+            can_hold()?;
+            enough_energy(robot, content_in.properties().cost() * amount).and_then(take)
+        }
+        | (TileType::Mountain, _, Content::None) => {
+            let most_rocks = Content::Rock(0).properties().max() - 1;
+            enough_energy(robot, Content::Rock(0).properties().cost() * most_rocks * 4)
+        }
+        | (_, Content::Fire, Content::Water(_)) => enough_energy(robot, content_in.properties().cost()).and_then(take),
+        | (_, Content::Fire, _) => enough_energy(robot, Content::Water(0).properties().cost() * amount).and_then(take),
+        | (_, Content::None, _) => {
+            can_hold()?;
+            enough_energy(robot, content_in.properties().cost() * amount).and_then(take)
+        }
+        | (_, a, b) => {
+            if a.to_default() != b.to_default() {
+                return Err(WrongContentUsed);
+            }
+            let value = a.get_value().0.ok_or(OperationNotAllowed)?;
+            let amount = min(amount, a.properties().max().saturating_sub(value));
+            if amount == 0 {
+                return Err(OperationNotAllowed);
+            }
+            enough_energy(robot, content_in.properties().cost() * amount).and_then(take)
+        }
+    }
+}
+
+/// Given the robot and the content, returns the energy `craft` would use, without changing the backpack
+///
+/// # Usage
+/// ```rust
+/// use robotics_lib::interface::estimate_craft;
+/// ```
+///
+/// # Returns
+/// - `Ok`: The energy `craft` would use
+/// - `Err`: The error `craft` would return
+///
+/// # Errors
+/// The same as `craft`
+pub fn estimate_craft(robot: &impl Runnable, content: Content) -> Result<usize, LibError> {
+    simulate(robot, |robot| craft(robot, content))
+}
+
+/// The room left in the backpack
+fn backpack_space(robot: &impl Runnable) -> usize {
+    robot.get_backpack().size - robot.get_backpack().contents.values().sum::<usize>()
+}
+
+/// # Returns
+/// - `Ok`: The cost, if the robot has enough energy
+/// - `Err(NotEnoughEnergy)`: Otherwise
+fn enough_energy(robot: &impl Runnable, cost: usize) -> Result<usize, LibError> {
+    if robot.get_energy().has_enough_energy(cost) {
+        Ok(cost)
+    } else {
+        Err(NotEnoughEnergy)
+    }
+}
+
+/// A copy of a robot used by the estimates, it ignores the events
+#[derive(RobotState)]
+struct ScratchRobot(Robot);
+
+impl Runnable for ScratchRobot {
+    fn process_tick(&mut self, _world: &mut World) {}
+    fn handle_event(&mut self, _event: Event) {}
+}
+
+/// Plays the action on a copy of the robot, the events it fires are not recorded for the observers
+///
+/// # Returns
+/// The energy used by the action, or its error
+fn simulate<T>(
+    robot: &impl Runnable,
+    action: impl FnOnce(&mut ScratchRobot) -> Result<T, LibError>,
+) -> Result<usize, LibError> {
+    let energy = robot.get_energy();
+    let mut scratch = ScratchRobot(Robot {
+        energy: Energy::with_max(energy.get_energy_level(), energy.get_max_energy_level()),
+        coordinate: Coordinate::new(robot.get_coordinate().get_row(), robot.get_coordinate().get_col()),
+        backpack: BackPack {
+            size: robot.get_backpack().size,
+            contents: robot.get_backpack().contents.clone(),
+        },
+    });
+
+    without_recording(|| action(&mut scratch))?;
+    Ok(energy.get_energy_level() - scratch.get_energy().get_energy_level())
+}
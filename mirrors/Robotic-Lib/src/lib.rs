@@ -1,7 +1,11 @@
+// lets the code generated by robotics_lib_derive name this crate from inside it
+extern crate self as robotics_lib;
+
 pub mod energy;
 pub mod event;
 pub mod interface;
 pub mod runner;
+pub mod testing;
 #[cfg(test)]
 mod tests;
 pub mod utils;
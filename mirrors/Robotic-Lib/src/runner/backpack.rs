@@ -1,5 +1,6 @@
 use std::collections::HashMap;
 
+use serde::{Deserialize, Serialize};
 use strum::IntoEnumIterator;
 
 use crate::world::tile::Content;
@@ -14,7 +15,7 @@ use crate::world::tile::Content;
 /// # Parameters
 /// - `size`: The size of the backpack
 /// - `contents`: The contents of the backpack
-#[derive(Debug)]
+#[derive(Debug, Serialize, Deserialize)]
 pub struct BackPack {
     pub(crate) size: usize,
     pub(crate) contents: HashMap<Content, usize>,
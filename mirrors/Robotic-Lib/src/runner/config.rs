@@ -0,0 +1,70 @@
+use crate::energy::MAX_ENERGY_LEVEL;
+use crate::runner::outcome::EndCondition;
+use crate::world::environmental_conditions::EnvironmentalConditions;
+
+/// The rules of a game, given to `Runner::with_config`.
+///
+/// The default config gives the same game as `Runner::new`.
+///
+/// # Fields
+/// - `recharge`: The energy given to every robot at the end of a tick, it can depend on the weather and the time of day
+/// - `initial_energy`: The energy of every robot when the game starts, `None` keeps the energy the robot was built with
+/// - `max_energy`: The maximum energy level of every robot (default: 1000)
+/// - `backpack_size`: The size of the backpack of every robot (default: 20)
+/// - `discoverable`: The number of tiles that can be discovered with `discover_tiles`, given the dimension of the world
+///   (default: 30% of the world's size)
+/// - `tick_limit`: The number of ticks after which the game ends, `None` means no limit
+/// - `end_conditions`: The other ways for the game to end, checked at the end of every tick
+///
+/// # Example
+/// ```rust
+/// use robotics_lib::runner::config::RunnerConfig;
+/// use robotics_lib::runner::outcome::EndCondition;
+/// use robotics_lib::world::environmental_conditions::WeatherType;
+///
+/// // solar powered robots on a small battery
+/// let config = RunnerConfig {
+///     recharge: |environmental_conditions| match environmental_conditions.get_weather_condition() {
+///         WeatherType::Sunny => 20,
+///         _ => 5,
+///     },
+///     max_energy: 300,
+///     tick_limit: Some(500),
+///     end_conditions: vec![EndCondition::ScoreReached(0.5)],
+///     ..Default::default()
+/// };
+/// ```
+#[derive(Debug, Clone)]
+pub struct RunnerConfig {
+    pub recharge: fn(&EnvironmentalConditions) -> usize,
+    pub initial_energy: Option<usize>,
+    pub max_energy: usize,
+    pub backpack_size: usize,
+    pub discoverable: fn(usize) -> usize,
+    pub tick_limit: Option<usize>,
+    pub end_conditions: Vec<EndCondition>,
+}
+
+impl Default for RunnerConfig {
+    fn default() -> Self {
+        RunnerConfig {
+            recharge: default_recharge,
+            initial_energy: None,
+            max_energy: MAX_ENERGY_LEVEL,
+            backpack_size: 20,
+            discoverable: default_discoverable,
+            tick_limit: None,
+            end_conditions: vec![],
+        }
+    }
+}
+
+/// The recharge of `Runner::new`: 10 energy every tick, whatever the weather
+pub fn default_recharge(_environmental_conditions: &EnvironmentalConditions) -> usize {
+    10
+}
+
+/// The discovery budget of `Runner::new`: 30% of the world's size
+pub fn default_discoverable(dimension: usize) -> usize {
+    (dimension.pow(2) / 10 + 1) * 3
+}
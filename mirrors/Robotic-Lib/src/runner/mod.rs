@@ -1,16 +1,31 @@
 use crate::event::events::Event;
-use crate::event::events::Event::{DayChanged, EnergyRecharged, Ready, TimeChanged};
+use crate::event::events::Event::{DayChanged, EnergyRecharged, Ready, Terminated, TimeChanged, WeatherChanged};
 
+use crate::event::{start_recording, stop_recording};
 use crate::runner::backpack::BackPack;
+use crate::runner::config::RunnerConfig;
+use crate::runner::observer::{Observer, RobotStatus, TickSnapshot};
+use crate::runner::outcome::{EndCondition, EndReason, GameOutcome};
+use crate::runner::snapshot::{RunnerSnapshot, SaveState, SnapshotError};
 use crate::utils::LibError;
 use crate::world::coordinates::Coordinate;
+use crate::world::tile::Content;
 use crate::world::tile::TileType::Teleport;
 use crate::world::world_generator::{check_world, Generator};
 use crate::world::World;
 
+use std::fs::File;
+use std::io::{BufReader, BufWriter};
+
 use super::energy::{Energy, MAX_ENERGY_LEVEL};
 
 pub mod backpack;
+pub mod config;
+pub mod observer;
+pub mod outcome;
+pub mod snapshot;
+
+pub use robotics_lib_derive::RobotState;
 
 /// Represents the robot:
 /// - `energy`: The energy level of the robot.
@@ -52,20 +67,44 @@ impl Default for Robot {
     }
 }
 
+impl RobotState for Robot {
+    fn get_energy(&self) -> &Energy {
+        &self.energy
+    }
+    fn get_energy_mut(&mut self) -> &mut Energy {
+        &mut self.energy
+    }
+    fn get_coordinate(&self) -> &Coordinate {
+        &self.coordinate
+    }
+    fn get_coordinate_mut(&mut self) -> &mut Coordinate {
+        &mut self.coordinate
+    }
+    fn get_backpack(&self) -> &BackPack {
+        &self.backpack
+    }
+    fn get_backpack_mut(&mut self) -> &mut BackPack {
+        &mut self.backpack
+    }
+}
+
 /// Container for everything needed to run the robot.
 ///
 /// ## Fields
-/// - `robot`: A pointer to an implementation of the `Runnable` trait.
+/// - `robots`: Pointers to implementations of the `Runnable` trait, in turn order.
 /// - `world`: Reppresents the game world.
+/// - `config`: The rules of the game.
+/// - `ticks`: The number of ticks played so far.
+/// - `energy_spent`: The energy consumed by every robot so far.
+/// - `out_of_energy_ticks`: For every robot, the number of ticks in a row it ended with zero energy.
+/// - `outcome`: How the game ended, `None` while it is still running.
+/// - `observers`: The observers watching the game.
 ///
 /// # Usage
 /// ```
 /// # use std::collections::HashMap;
-/// use robotics_lib::energy::Energy;
 /// # use robotics_lib::event::events::Event;
-/// # use robotics_lib::runner::{Robot, Runnable};
-/// # use robotics_lib::runner::backpack::BackPack;
-/// # use robotics_lib::world::coordinates::Coordinate;
+/// # use robotics_lib::runner::{Robot, RobotState, Runnable};
 /// # use robotics_lib::world::World;
 /// # use robotics_lib::world::world_generator::Generator;
 /// # use robotics_lib::world::tile::{Content, Tile};
@@ -81,6 +120,7 @@ impl Default for Robot {
 /// #    }
 ///  }
 ///
+///  #[derive(RobotState)]
 ///  struct MyRobot(Robot);
 ///  impl Runnable for MyRobot{
 /// #    fn process_tick(&mut self, world: &mut World) {
@@ -89,24 +129,6 @@ impl Default for Robot {
 /// #    fn handle_event(&mut self, event: Event) {
 /// #        // react to this event in your GUI
 /// #    }
-/// #    fn get_energy(&self) -> &Energy {
-/// #        &self.0.energy
-/// #    }
-/// #    fn get_energy_mut(&mut self) -> &mut Energy {
-/// #        &mut self.0.energy
-/// #    }
-/// #    fn get_coordinate(&self) -> &Coordinate {
-/// #       &self.0.coordinate
-/// #    }
-/// #    fn get_coordinate_mut(&mut self) -> &mut Coordinate{
-/// #        &mut self.0.coordinate
-/// #    }
-/// #    fn get_backpack(&self) -> &BackPack {
-/// #        &self.0.backpack
-/// #    }
-/// #    fn get_backpack_mut(&mut self) -> &mut BackPack {
-/// #        &mut self.0.backpack
-/// #    }
 ///  }
 ///  impl Generator for WorldGenerator {
 /// #  fn gen(&mut self) -> (Vec<Vec<Tile>>, (usize, usize), EnvironmentalConditions, f32, Option<HashMap<Content, f32>>){todo!()}
@@ -123,13 +145,68 @@ impl Default for Robot {
 /// }
 /// ```
 pub struct Runner {
-    robot: Box<dyn Runnable>,
-    world: World,
+    robots: Vec<Box<dyn Runnable>>,
+    pub(crate) world: World,
+    config: RunnerConfig,
+    ticks: usize,
+    energy_spent: Vec<usize>,
+    out_of_energy_ticks: Vec<usize>,
+    outcome: Option<GameOutcome>,
+    observers: Vec<Box<dyn Observer>>,
+}
+
+/// Bound added to every `Runnable`.
+///
+/// With the `send` feature it requires `Send`, which makes the `Runner` `Send` so that a game can be built on one
+/// thread and played on another (like a worker of a thread pool). Without the feature it requires nothing.
+#[cfg(feature = "send")]
+pub trait MaybeSend: Send {}
+#[cfg(feature = "send")]
+impl<T: Send + ?Sized> MaybeSend for T {}
+
+/// Bound added to every `Runnable`, it requires nothing without the `send` feature.
+#[cfg(not(feature = "send"))]
+pub trait MaybeSend {}
+#[cfg(not(feature = "send"))]
+impl<T: ?Sized> MaybeSend for T {}
+
+/// The energy, the coordinate and the backpack of a robot, that the `Runner` and the interface read and update.
+///
+/// `Robot` implements it, so a robot built around a `Robot` can derive it with `#[derive(RobotState)]`:
+/// the derive delegates the accessors to the field marked with `#[robot]`, which can be left out when the struct
+/// has a single field.
+///
+/// # Example
+/// ```rust
+/// use robotics_lib::runner::{Robot, RobotState};
+///
+/// #[derive(RobotState)]
+/// struct MyRobot {
+///     #[robot]
+///     robot: Robot,
+///     steps: usize,
+/// }
+///
+/// let robot = MyRobot { robot: Robot::new(), steps: 0 };
+/// assert_eq!(robot.get_coordinate(), &robot.robot.coordinate);
+/// ```
+pub trait RobotState {
+    fn get_energy(&self) -> &Energy;
+    fn get_energy_mut(&mut self) -> &mut Energy;
+    fn get_coordinate(&self) -> &Coordinate;
+    fn get_coordinate_mut(&mut self) -> &mut Coordinate;
+    fn get_backpack(&self) -> &BackPack;
+    fn get_backpack_mut(&mut self) -> &mut BackPack;
 }
 
 /// Represents the necessary functionality for a robot to be able to run
 /// The `Runnable` trait is used to define the necessary functionality for a robot to be able to run.
 ///
+/// The accessors to the energy, the coordinate and the backpack are in `RobotState`, which can be derived.
+///
+/// `get_save_state` and `get_save_state_mut` return `None` by default, a robot that wants its internal state
+/// in the snapshots of the `Runner` implements `SaveState` and returns `Some(self)` from both.
+///
 /// # Usage
 /// ```
 /// use robotics_lib::runner::{Runnable};
@@ -138,13 +215,11 @@ pub struct Runner {
 /// # Example
 ///
 /// ```rust
-/// use robotics_lib::energy::Energy;
 /// use robotics_lib::event::events::Event;
-/// use robotics_lib::runner::{Robot, Runnable};
-/// use robotics_lib::runner::backpack::BackPack;
-/// use robotics_lib::world::coordinates::Coordinate;
+/// use robotics_lib::runner::{Robot, RobotState, Runnable};
 /// use robotics_lib::world::World;
 ///
+/// #[derive(RobotState)]
 /// struct MyRobot(Robot);
 /// impl Runnable for MyRobot{
 ///     fn process_tick(&mut self, world: &mut World) {
@@ -153,35 +228,17 @@ pub struct Runner {
 ///     fn handle_event(&mut self, event: Event) {
 ///         // react to this event in your GUI
 ///     }
-///     fn get_energy(&self) -> &Energy {
-///         &self.0.energy
-///     }
-///     fn get_energy_mut(&mut self) -> &mut Energy {
-///         &mut self.0.energy
-///     }
-///     fn get_coordinate(&self) -> &Coordinate {
-///        &self.0.coordinate
-///     }
-///     fn get_coordinate_mut(&mut self) -> &mut Coordinate{
-///         &mut self.0.coordinate
-///     }
-///     fn get_backpack(&self) -> &BackPack {
-///         &self.0.backpack
-///     }
-///     fn get_backpack_mut(&mut self) -> &mut BackPack {
-///         &mut self.0.backpack
-///     }
 /// }
 /// ```
-pub trait Runnable {
+pub trait Runnable: MaybeSend + RobotState {
     fn process_tick(&mut self, world: &mut World);
     fn handle_event(&mut self, event: Event);
-    fn get_energy(&self) -> &Energy;
-    fn get_energy_mut(&mut self) -> &mut Energy;
-    fn get_coordinate(&self) -> &Coordinate;
-    fn get_coordinate_mut(&mut self) -> &mut Coordinate;
-    fn get_backpack(&self) -> &BackPack;
-    fn get_backpack_mut(&mut self) -> &mut BackPack;
+    fn get_save_state(&self) -> Option<&dyn SaveState> {
+        None
+    }
+    fn get_save_state_mut(&mut self) -> Option<&mut dyn SaveState> {
+        None
+    }
 }
 
 impl Runner {
@@ -197,11 +254,8 @@ impl Runner {
     /// # Example
     /// ```
     /// # use std::collections::HashMap;
-    /// use robotics_lib::energy::Energy;
     /// # use robotics_lib::event::events::Event;
-    /// # use robotics_lib::runner::{Robot, Runnable};
-    /// # use robotics_lib::runner::backpack::BackPack;
-    /// # use robotics_lib::world::coordinates::Coordinate;
+    /// # use robotics_lib::runner::{Robot, RobotState, Runnable};
     /// # use robotics_lib::world::World;
     /// # use robotics_lib::world::world_generator::Generator;
     /// # use robotics_lib::world::tile::{Content, Tile};
@@ -217,6 +271,7 @@ impl Runner {
     /// #    }
     ///  }
     ///
+    ///  #[derive(RobotState)]
     ///  struct MyRobot(Robot);
     ///  impl Runnable for MyRobot{
     /// #    fn process_tick(&mut self, world: &mut World) {
@@ -225,24 +280,6 @@ impl Runner {
     /// #    fn handle_event(&mut self, event: Event) {
     /// #        // react to this event in your GUI
     /// #    }
-    /// #    fn get_energy(&self) -> &Energy {
-    /// #        &self.0.energy
-    /// #    }
-    /// #    fn get_energy_mut(&mut self) -> &mut Energy {
-    /// #        &mut self.0.energy
-    /// #    }
-    /// #    fn get_coordinate(&self) -> &Coordinate {
-    /// #       &self.0.coordinate
-    /// #    }
-    /// #    fn get_coordinate_mut(&mut self) -> &mut Coordinate{
-    /// #        &mut self.0.coordinate
-    /// #    }
-    /// #    fn get_backpack(&self) -> &BackPack {
-    /// #        &self.0.backpack
-    /// #    }
-    /// #    fn get_backpack_mut(&mut self) -> &mut BackPack {
-    /// #        &mut self.0.backpack
-    /// #    }
     ///  }
     ///  impl Generator for WorldGenerator {
     /// #  fn gen(&mut self) -> (Vec<Vec<Tile>>, (usize, usize), EnvironmentalConditions, f32, Option<HashMap<Content, f32>>){todo!()}
@@ -262,25 +299,89 @@ impl Runner {
     ///
     /// # Return
     /// An instance of Runner.
-    pub fn new(mut robot: Box<dyn Runnable>, generator: &mut impl Generator) -> Result<Runner, LibError> {
-        let (mut map, (robot_x, robot_y), environmental_conditions, max_score, score_table) = generator.gen();
+    pub fn new(robot: Box<dyn Runnable>, generator: &mut impl Generator) -> Result<Runner, LibError> {
+        Runner::new_multi(vec![robot], generator)
+    }
+
+    /// Initializes the Runner with more robots sharing the same world
+    /// Works like `new`, but every robot gets its own spawn point from `Generator::gen_spawn_points`.
+    /// Each robot keeps its own energy, backpack, discovered tiles and score.
+    ///
+    /// # Turn order
+    /// In every tick the robots play one after the other, in the order of `robots`.
+    ///
+    /// # Errors
+    /// - `NoRobots`: `robots` is empty
+    /// - `NotEnoughSpawnPoints`: The generator didn't give a distinct, in bounds spawn point for every robot
+    ///
+    /// # Return
+    /// An instance of Runner.
+    pub fn new_multi(robots: Vec<Box<dyn Runnable>>, generator: &mut impl Generator) -> Result<Runner, LibError> {
+        Runner::with_config(robots, generator, RunnerConfig::default())
+    }
+
+    /// Initializes the Runner with custom game rules
+    /// Works like `new_multi`, but the recharge, the energy, the backpack size, the discovery budget and the tick
+    /// limit are taken from `config`.
+    ///
+    /// # Errors
+    /// Same as `new_multi`
+    ///
+    /// # Return
+    /// An instance of Runner.
+    pub fn with_config(
+        mut robots: Vec<Box<dyn Runnable>>,
+        generator: &mut impl Generator,
+        config: RunnerConfig,
+    ) -> Result<Runner, LibError> {
+        if robots.is_empty() {
+            return Err(LibError::NoRobots);
+        }
+        let (mut map, spawn, environmental_conditions, max_score, score_table) = generator.gen();
 
         check_world(&map)?; //check if the world is valid
 
-        *(robot.get_coordinate_mut()) = Coordinate::new(robot_x, robot_y);
-        robot.get_backpack_mut().size = 20;
+        let spawn_points = generator.gen_spawn_points(&map, spawn, robots.len());
+        let mut distinct = spawn_points.clone();
+        distinct.sort();
+        distinct.dedup();
+        if spawn_points.len() != robots.len()
+            || distinct.len() != spawn_points.len()
+            || spawn_points
+                .iter()
+                .any(|(row, col)| *row >= map.len() || *col >= map.len())
+        {
+            return Err(LibError::NotEnoughSpawnPoints);
+        }
 
-        if let Teleport(value) = map[robot_x][robot_y].tile_type {
-            if !value {
-                map[robot_x][robot_y].tile_type = Teleport(true);
+        for (robot, (robot_x, robot_y)) in robots.iter_mut().zip(&spawn_points) {
+            *(robot.get_coordinate_mut()) = Coordinate::new(*robot_x, *robot_y);
+            robot.get_backpack_mut().size = config.backpack_size;
+            let energy_level = config.initial_energy.unwrap_or(robot.get_energy().get_energy_level());
+            *robot.get_energy_mut() = Energy::with_max(energy_level, config.max_energy);
+
+            if let Teleport(value) = map[*robot_x][*robot_y].tile_type {
+                if !value {
+                    map[*robot_x][*robot_y].tile_type = Teleport(true);
+                }
             }
+
+            robot.handle_event(Ready);
         }
 
-        robot.handle_event(Ready);
+        let mut world = World::new(map, environmental_conditions, max_score, score_table);
+        world.discoverable = (config.discoverable)(world.dimension);
+        world.add_robots(spawn_points);
 
         Ok(Runner {
-            robot,
-            world: World::new(map, environmental_conditions, max_score, score_table),
+            energy_spent: vec![0; robots.len()],
+            out_of_energy_ticks: vec![0; robots.len()],
+            robots,
+            world,
+            config,
+            ticks: 0,
+            outcome: None,
+            observers: vec![],
         })
     }
 
@@ -289,11 +390,8 @@ impl Runner {
     /// # Example
     /// ```
     /// # use std::collections::HashMap;
-    /// use robotics_lib::energy::Energy;
     /// # use robotics_lib::event::events::Event;
-    /// # use robotics_lib::runner::{Robot, Runnable};
-    /// # use robotics_lib::runner::backpack::BackPack;
-    /// # use robotics_lib::world::coordinates::Coordinate;
+    /// # use robotics_lib::runner::{Robot, RobotState, Runnable};
     /// # use robotics_lib::world::World;
     /// # use robotics_lib::world::world_generator::Generator;
     /// # use robotics_lib::world::tile::{Content, Tile};
@@ -309,6 +407,7 @@ impl Runner {
     /// #    }
     ///  }
     ///
+    ///  #[derive(RobotState)]
     ///  struct MyRobot(Robot);
     ///  impl Runnable for MyRobot{
     /// #    fn process_tick(&mut self, world: &mut World) {
@@ -317,24 +416,6 @@ impl Runner {
     /// #    fn handle_event(&mut self, event: Event) {
     /// #        // react to this event in your GUI
     /// #    }
-    /// #    fn get_energy(&self) -> &Energy {
-    /// #        &self.0.energy
-    /// #    }
-    /// #    fn get_energy_mut(&mut self) -> &mut Energy {
-    /// #        &mut self.0.energy
-    /// #    }
-    /// #    fn get_coordinate(&self) -> &Coordinate {
-    /// #       &self.0.coordinate
-    /// #    }
-    /// #    fn get_coordinate_mut(&mut self) -> &mut Coordinate{
-    /// #        &mut self.0.coordinate
-    /// #    }
-    /// #    fn get_backpack(&self) -> &BackPack {
-    /// #        &self.0.backpack
-    /// #    }
-    /// #    fn get_backpack_mut(&mut self) -> &mut BackPack {
-    /// #        &mut self.0.backpack
-    /// #    }
     ///  }
     ///  impl Generator for WorldGenerator {
     /// #  fn gen(&mut self) -> (Vec<Vec<Tile>>, (usize, usize), EnvironmentalConditions, f32, Option<HashMap<Content, f32>>){todo!()}
@@ -357,25 +438,295 @@ impl Runner {
     /// }
     /// ```
     pub fn game_tick(&mut self) -> Result<(), LibError> {
+        if self.outcome.is_some() {
+            return Err(LibError::GameOver);
+        }
+
         //add other update functions here
-        if self.world.advance_time() {
-            self.robot
-                .handle_event(DayChanged(self.world.environmental_conditions.clone()))
-        } else {
-            self.robot
-                .handle_event(TimeChanged(self.world.environmental_conditions.clone()))
+        let weather = self.world.environmental_conditions.get_weather_condition();
+        let day_changed = self.world.advance_time();
+        let new_weather = self.world.environmental_conditions.get_weather_condition();
+        for robot in 0..self.robots.len() {
+            if day_changed {
+                self.send_event(robot, DayChanged(self.world.environmental_conditions.clone()));
+                if new_weather != weather {
+                    self.send_event(robot, WeatherChanged(new_weather));
+                }
+            } else {
+                self.send_event(robot, TimeChanged(self.world.environmental_conditions.clone()))
+            }
+        }
+
+        //the robots play one at a time, seeing where the others ended their turn
+        for active in 0..self.robots.len() {
+            let positions = self
+                .robots
+                .iter()
+                .map(|robot| (robot.get_coordinate().get_row(), robot.get_coordinate().get_col()))
+                .collect();
+            let energy_before = self.robots[active].get_energy().get_energy_level();
+            self.world.start_turn(active, positions);
+            // the events of the turn are only recorded if someone is watching
+            if self.observers.is_empty() {
+                self.robots[active].process_tick(&mut self.world);
+            } else {
+                start_recording();
+                self.robots[active].process_tick(&mut self.world);
+                for event in stop_recording() {
+                    for observer in self.observers.iter_mut() {
+                        observer.on_event(active, &event);
+                    }
+                }
+            }
+            self.world.end_turn();
+
+            let energy_after = self.robots[active].get_energy().get_energy_level();
+            self.energy_spent[active] += energy_before.saturating_sub(energy_after);
+            if energy_after == 0 {
+                self.out_of_energy_ticks[active] += 1;
+            } else {
+                self.out_of_energy_ticks[active] = 0;
+            }
         }
 
-        self.robot.process_tick(&mut self.world);
+        let energy_to_add = (self.config.recharge)(&self.world.environmental_conditions);
+        for robot in 0..self.robots.len() {
+            self.robots[robot].get_energy_mut().recharge_energy(energy_to_add);
+            self.send_event(robot, EnergyRecharged(energy_to_add));
+        }
 
-        let energy_to_add = 10;
-        self.robot.get_energy_mut().recharge_energy(energy_to_add);
-        self.robot.handle_event(EnergyRecharged(energy_to_add));
+        self.ticks += 1;
+        if !self.observers.is_empty() {
+            let snapshot = self.tick_snapshot();
+            for observer in self.observers.iter_mut() {
+                observer.on_tick(&snapshot);
+            }
+        }
+        if let Some(reason) = self.end_reason() {
+            self.end(reason);
+        }
         Ok(())
     }
 
-    ///Returns an immutable reference to the `robot` field of the `Runner` struct.
+    /// Plays ticks until the game ends
+    ///
+    /// # Errors
+    /// - `NoEndCondition`: The config has no tick limit and no end condition, the game would never end
+    /// - `GameOver`: The game had already ended
+    ///
+    /// # Return
+    /// How the game ended.
+    pub fn run_to_completion(&mut self) -> Result<GameOutcome, LibError> {
+        if self.config.tick_limit.is_none() && self.config.end_conditions.is_empty() {
+            return Err(LibError::NoEndCondition);
+        }
+        if self.outcome.is_some() {
+            return Err(LibError::GameOver);
+        }
+
+        loop {
+            self.game_tick()?;
+            if let Some(outcome) = &self.outcome {
+                return Ok(outcome.clone());
+            }
+        }
+    }
+
+    /// Ends the game now, the robots receive `Terminated`
+    ///
+    /// # Return
+    /// How the game ended, the outcome of the game if it had already ended.
+    pub fn terminate(&mut self) -> GameOutcome {
+        match &self.outcome {
+            | Some(outcome) => outcome.clone(),
+            | None => self.end(EndReason::Stopped),
+        }
+    }
+
+    ///Returns how the game ended, `None` while it is still running.
+    pub fn get_outcome(&self) -> Option<&GameOutcome> {
+        self.outcome.as_ref()
+    }
+
+    /// # Returns
+    /// The reason to end the game, if the tick limit or one of the end conditions was reached
+    fn end_reason(&self) -> Option<EndReason> {
+        if self
+            .config
+            .tick_limit
+            .is_some_and(|tick_limit| self.ticks >= tick_limit)
+        {
+            return Some(EndReason::TickLimit);
+        }
+
+        let score = self.world.score_counter.get_score();
+        let max_score = self.world.score_counter.max_score;
+        self.config.end_conditions.iter().find_map(|condition| {
+            let ended = match condition {
+                | EndCondition::ScoreReached(fraction) => score >= fraction * max_score,
+                | EndCondition::OutOfEnergy(ticks) => {
+                    !self.robots.is_empty()
+                        && self
+                            .out_of_energy_ticks
+                            .iter()
+                            .all(|out_of_energy| out_of_energy >= ticks)
+                }
+                | EndCondition::NoCollectables => !self
+                    .world
+                    .map
+                    .iter()
+                    .flatten()
+                    .any(|tile| tile.content != Content::None && tile.content.properties().destroy()),
+            };
+            let reason = match condition {
+                | EndCondition::ScoreReached(_) => EndReason::ScoreReached,
+                | EndCondition::OutOfEnergy(_) => EndReason::OutOfEnergy,
+                | EndCondition::NoCollectables => EndReason::NoCollectables,
+            };
+            ended.then_some(reason)
+        })
+    }
+
+    /// Registers an observer, it receives the events of every robot and a snapshot of the game after every tick
+    ///
+    /// # Arguments
+    /// - `observer`: The observer to add, it is kept until the runner is dropped
+    pub fn add_observer(&mut self, observer: Box<dyn Observer>) {
+        self.observers.push(observer);
+    }
+
+    /// Gives the event to the robot and to the observers
+    fn send_event(&mut self, robot: usize, event: Event) {
+        for observer in self.observers.iter_mut() {
+            observer.on_event(robot, &event);
+        }
+        self.robots[robot].handle_event(event);
+    }
+
+    /// # Returns
+    /// The state of the game given to the observers
+    fn tick_snapshot(&self) -> TickSnapshot {
+        let scores = self.world.robot_scores();
+        TickSnapshot {
+            tick: self.ticks,
+            score: self.world.score_counter.get_score(),
+            environmental_conditions: self.world.environmental_conditions.clone(),
+            robots: self
+                .robots
+                .iter()
+                .enumerate()
+                .map(|(index, robot)| RobotStatus {
+                    position: (robot.get_coordinate().get_row(), robot.get_coordinate().get_col()),
+                    energy: robot.get_energy().get_energy_level(),
+                    backpack: robot.get_backpack().get_contents().clone(),
+                    map: self.world.discovered_map(index),
+                    score: scores[index],
+                })
+                .collect(),
+        }
+    }
+
+    /// Stores the outcome of the game and sends `Terminated` to the robots
+    fn end(&mut self, reason: EndReason) -> GameOutcome {
+        let outcome = GameOutcome {
+            reason,
+            ticks: self.ticks,
+            score: self.world.score_counter.get_score(),
+            scores: self.world.robot_scores(),
+            energy_spent: self.energy_spent.iter().sum(),
+        };
+        for robot in 0..self.robots.len() {
+            self.send_event(robot, Terminated);
+        }
+        self.outcome = Some(outcome.clone());
+        outcome
+    }
+
+    ///Returns an immutable reference to the first robot of the `Runner` struct, a Runner always has at least one.
     pub fn get_robot(&self) -> &Box<dyn Runnable> {
-        &self.robot
+        &self.robots[0]
+    }
+
+    ///Returns an immutable reference to the `robots` field of the `Runner` struct, in turn order.
+    pub fn get_robots(&self) -> &Vec<Box<dyn Runnable>> {
+        &self.robots
+    }
+
+    /// Saves the full state of the game in a file, so that it can be restored with `restore` or `restore_multi`
+    ///
+    /// The snapshot contains the world, the score, the discovered tiles and the energy, coordinate and backpack
+    /// of every robot, plus the internal state of the robots implementing `SaveState`.
+    ///
+    /// # Errors
+    /// - `Io`: The file couldn't be written
+    /// - `Format`: The state couldn't be serialized
+    pub fn save(&self, path: &str) -> Result<(), SnapshotError> {
+        let file = File::create(path)?;
+        let snapshot = RunnerSnapshot::new(self);
+        bincode::serialize_into(BufWriter::new(file), &snapshot)?;
+        Ok(())
+    }
+
+    /// Restores a game saved with `save`
+    /// The robot is given back its energy, coordinate and backpack, and its internal state if it implements
+    /// `SaveState`.
+    ///
+    /// # Errors
+    /// - `Io`: The file couldn't be read
+    /// - `Format`: The file is not a valid snapshot
+    /// - `WrongVersion`: The snapshot was saved with another version of the library
+    /// - `WrongRobotCount`: The snapshot was saved by a game with more robots
+    /// - `RobotState`: The robot refused its saved state
+    ///
+    /// # Return
+    /// An instance of Runner.
+    pub fn restore(robot: Box<dyn Runnable>, path: &str) -> Result<Runner, SnapshotError> {
+        Runner::restore_multi(vec![robot], path)
+    }
+
+    /// Restores a game with more robots saved with `save`, the robots must be given in the same turn order
+    ///
+    /// # Errors
+    /// Same as `restore`
+    ///
+    /// # Return
+    /// An instance of Runner.
+    pub fn restore_multi(robots: Vec<Box<dyn Runnable>>, path: &str) -> Result<Runner, SnapshotError> {
+        Runner::restore_with_config(robots, path, RunnerConfig::default())
+    }
+
+    /// Restores a game saved with `save`, playing the next ticks with the rules in `config`
+    /// The energy levels and the discovery budget are the saved ones, only the recharge and the tick limit of
+    /// `config` apply.
+    ///
+    /// # Errors
+    /// Same as `restore`
+    ///
+    /// # Return
+    /// An instance of Runner.
+    pub fn restore_with_config(
+        robots: Vec<Box<dyn Runnable>>,
+        path: &str,
+        config: RunnerConfig,
+    ) -> Result<Runner, SnapshotError> {
+        let file = File::open(path)?;
+        let snapshot: RunnerSnapshot = bincode::deserialize_from(BufReader::new(file))?;
+        let mut runner = snapshot.restore(robots, config)?;
+
+        for robot in runner.robots.iter_mut() {
+            robot.handle_event(Ready);
+        }
+
+        Ok(runner)
+    }
+
+    ///Returns the number of ticks played so far.
+    pub fn get_ticks(&self) -> usize {
+        self.ticks
+    }
+
+    ///Returns the score of every robot, in turn order.
+    pub fn get_scores(&self) -> Vec<f32> {
+        self.world.robot_scores()
     }
 }
@@ -0,0 +1,66 @@
+use std::collections::HashMap;
+
+use crate::event::events::Event;
+use crate::runner::MaybeSend;
+use crate::world::environmental_conditions::EnvironmentalConditions;
+use crate::world::tile::{Content, Tile};
+
+/// Watches a game without the cooperation of the robots, like a visualizer, a recorder or a metrics collector.
+///
+/// Observers are registered with `Runner::add_observer`, they receive every event received by the robots and a
+/// read-only snapshot of the game at the end of every tick. Both methods do nothing by default.
+///
+/// # Example
+/// ```rust
+/// use robotics_lib::event::events::Event;
+/// use robotics_lib::runner::observer::{Observer, TickSnapshot};
+///
+/// struct Logger;
+///
+/// impl Observer for Logger {
+///     fn on_event(&mut self, robot: usize, event: &Event) {
+///         println!("robot {}: {}", robot, event);
+///     }
+///     fn on_tick(&mut self, snapshot: &TickSnapshot) {
+///         println!("tick {}: score {}", snapshot.tick, snapshot.score);
+///     }
+/// }
+/// ```
+pub trait Observer: MaybeSend {
+    /// Called for every event received by a robot, `robot` is its index in the `Runner`
+    fn on_event(&mut self, _robot: usize, _event: &Event) {}
+    /// Called at the end of every tick, after the energy recharge
+    fn on_tick(&mut self, _snapshot: &TickSnapshot) {}
+}
+
+/// The state of the game at the end of a tick, given to `Observer::on_tick`.
+///
+/// # Fields
+/// - `tick`: The number of ticks played so far
+/// - `score`: The score of the world
+/// - `environmental_conditions`: The weather and the time of day
+/// - `robots`: The state of every robot, in turn order
+#[derive(Debug, Clone)]
+pub struct TickSnapshot {
+    pub tick: usize,
+    pub score: f32,
+    pub environmental_conditions: EnvironmentalConditions,
+    pub robots: Vec<RobotStatus>,
+}
+
+/// The state of a robot at the end of a tick.
+///
+/// # Fields
+/// - `position`: The (row, col) of the robot
+/// - `energy`: The energy level of the robot
+/// - `backpack`: The contents of the backpack of the robot
+/// - `map`: The tiles discovered by the robot, like `robot_map` returns them
+/// - `score`: The score made by the robot
+#[derive(Debug, Clone)]
+pub struct RobotStatus {
+    pub position: (usize, usize),
+    pub energy: usize,
+    pub backpack: HashMap<Content, usize>,
+    pub map: Vec<Vec<Option<Tile>>>,
+    pub score: f32,
+}
@@ -0,0 +1,50 @@
+use serde::{Deserialize, Serialize};
+
+/// A way for the game to end, checked by the `Runner` at the end of every tick.
+///
+/// # Variants
+/// - `ScoreReached(f32)`: The score of the world reached this fraction of its max score (1.0 is the max score)
+/// - `OutOfEnergy(usize)`: Every robot ended its turn with zero energy for this many ticks in a row
+/// - `NoCollectables`: No content that can be destroyed is left in the world
+///
+/// The tick limit of the `RunnerConfig` always ends the game too.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum EndCondition {
+    ScoreReached(f32),
+    OutOfEnergy(usize),
+    NoCollectables,
+}
+
+/// Why the game ended.
+///
+/// # Variants
+/// - `TickLimit`: The tick limit of the `RunnerConfig` was reached
+/// - `ScoreReached`: The `ScoreReached` end condition was met
+/// - `OutOfEnergy`: The `OutOfEnergy` end condition was met
+/// - `NoCollectables`: The `NoCollectables` end condition was met
+/// - `Stopped`: The game was ended with `Runner::terminate`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum EndReason {
+    TickLimit,
+    ScoreReached,
+    OutOfEnergy,
+    NoCollectables,
+    Stopped,
+}
+
+/// The result of a finished game, returned by `Runner::run_to_completion` and `Runner::terminate`.
+///
+/// # Fields
+/// - `reason`: Why the game ended
+/// - `ticks`: The number of ticks played
+/// - `score`: The final score of the world
+/// - `scores`: The final score of every robot, in turn order
+/// - `energy_spent`: The energy consumed by all the robots during the game
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct GameOutcome {
+    pub reason: EndReason,
+    pub ticks: usize,
+    pub score: f32,
+    pub scores: Vec<f32>,
+    pub energy_spent: usize,
+}
@@ -0,0 +1,247 @@
+use std::cell::RefCell;
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+use std::fmt::{Display, Formatter};
+use std::io;
+
+use serde::{Deserialize, Serialize};
+
+use crate::energy::Energy;
+use crate::runner::backpack::BackPack;
+use crate::runner::config::RunnerConfig;
+use crate::runner::outcome::GameOutcome;
+use crate::runner::{Runnable, Runner};
+use crate::world::coordinates::Coordinate;
+use crate::world::environmental_conditions::EnvironmentalConditions;
+use crate::world::score::ScoreCounter;
+use crate::world::tile::{Content, Tile};
+use crate::world::{RobotStates, World};
+
+/// Bumped whenever the layout of the snapshot changes, old snapshots are refused instead of misread
+pub(crate) const SNAPSHOT_VERSION: u32 = 3;
+
+/// Lets a robot save its own internal state in the snapshots of the `Runner`.
+///
+/// The runner already saves the energy, the coordinate and the backpack of every robot, this trait is only
+/// needed for what the robot keeps by itself (a plan, a memory of the map, ...).
+/// To opt in, implement this trait and return `Some(self)` from `Runnable::get_save_state` and
+/// `Runnable::get_save_state_mut`.
+///
+/// # Example
+/// ```rust
+/// use robotics_lib::runner::snapshot::{SaveState, SnapshotError};
+///
+/// struct Plan {
+///     steps_done: u8,
+/// }
+///
+/// impl SaveState for Plan {
+///     fn save_state(&self) -> Vec<u8> {
+///         vec![self.steps_done]
+///     }
+///     fn restore_state(&mut self, state: &[u8]) -> Result<(), SnapshotError> {
+///         self.steps_done = *state.first().ok_or(SnapshotError::RobotState("empty state".to_string()))?;
+///         Ok(())
+///     }
+/// }
+/// ```
+pub trait SaveState {
+    fn save_state(&self) -> Vec<u8>;
+    fn restore_state(&mut self, state: &[u8]) -> Result<(), SnapshotError>;
+}
+
+/// It contains all the errors that can happen while saving or restoring a snapshot
+///
+/// # Variants
+/// - `Io`: The snapshot file couldn't be read or written
+/// - `Format`: The snapshot file is not a valid snapshot
+/// - `WrongVersion(u32)`: The snapshot was saved with another version of the library
+/// - `WrongRobotCount`: The snapshot has a different number of robots than the ones given to restore it
+/// - `RobotState(String)`: A robot refused the state it saved
+#[derive(Debug)]
+pub enum SnapshotError {
+    Io(io::Error),
+    Format(bincode::Error),
+    WrongVersion(u32),
+    WrongRobotCount {
+        expected: usize,
+        found: usize,
+    },
+    RobotState(String),
+}
+
+impl Display for SnapshotError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            | SnapshotError::Io(error) => write!(f, "Snapshot file error: {}", error),
+            | SnapshotError::Format(error) => write!(f, "Invalid snapshot: {}", error),
+            | SnapshotError::WrongVersion(version) => write!(
+                f,
+                "Snapshot saved with version {}, expected {}",
+                version, SNAPSHOT_VERSION
+            ),
+            | SnapshotError::WrongRobotCount { expected, found } => {
+                write!(f, "Snapshot has {} robots, {} were given", expected, found)
+            }
+            | SnapshotError::RobotState(error) => write!(f, "Robot state error: {}", error),
+        }
+    }
+}
+
+impl From<io::Error> for SnapshotError {
+    fn from(error: io::Error) -> Self {
+        SnapshotError::Io(error)
+    }
+}
+
+impl From<bincode::Error> for SnapshotError {
+    fn from(error: bincode::Error) -> Self {
+        SnapshotError::Format(error)
+    }
+}
+
+/// What is saved of every robot
+#[derive(Serialize, Deserialize)]
+pub(crate) struct RobotSnapshot {
+    energy: Energy,
+    coordinate: Coordinate,
+    backpack: BackPack,
+    score: f32,
+    energy_spent: usize,
+    out_of_energy_ticks: usize,
+    discovered: Vec<(usize, usize)>,
+    state: Option<Vec<u8>>,
+}
+
+/// The full state of a running game
+#[derive(Serialize, Deserialize)]
+pub(crate) struct RunnerSnapshot {
+    version: u32,
+    ticks: usize,
+    outcome: Option<GameOutcome>,
+    map: Vec<Vec<Tile>>,
+    discoverable: usize,
+    environmental_conditions: EnvironmentalConditions,
+    score: f32,
+    max_score: f32,
+    score_table: HashMap<Content, f32>,
+    robots: Vec<RobotSnapshot>,
+}
+
+impl RunnerSnapshot {
+    /// Takes the snapshot of the world and of the robots playing in it
+    pub(crate) fn new(runner: &Runner) -> Self {
+        let world = &runner.world;
+        let scores = world.robot_scores();
+        let discovered = world.robots.discovered.borrow();
+
+        RunnerSnapshot {
+            version: SNAPSHOT_VERSION,
+            ticks: runner.ticks,
+            outcome: runner.outcome.clone(),
+            map: world.map.clone(),
+            discoverable: world.discoverable,
+            environmental_conditions: world.environmental_conditions.clone(),
+            score: world.score_counter.get_score(),
+            max_score: world.score_counter.max_score,
+            score_table: world.score_counter.score_table.clone(),
+            robots: runner
+                .robots
+                .iter()
+                .enumerate()
+                .map(|(index, robot)| RobotSnapshot {
+                    energy: Energy::with_max(
+                        robot.get_energy().get_energy_level(),
+                        robot.get_energy().get_max_energy_level(),
+                    ),
+                    coordinate: Coordinate::new(robot.get_coordinate().get_row(), robot.get_coordinate().get_col()),
+                    backpack: BackPack {
+                        size: robot.get_backpack().size,
+                        contents: robot.get_backpack().contents.clone(),
+                    },
+                    score: scores.get(index).cloned().unwrap_or_default(),
+                    energy_spent: runner.energy_spent[index],
+                    out_of_energy_ticks: runner.out_of_energy_ticks[index],
+                    discovered: discovered
+                        .get(index)
+                        .map(|tiles| tiles.iter().cloned().collect())
+                        .unwrap_or_default(),
+                    state: robot.get_save_state().map(|state| state.save_state()),
+                })
+                .collect(),
+        }
+    }
+
+    /// Gives the saved state back to the robots and rebuilds the game, which goes on with the rules in `config`
+    ///
+    /// # Errors
+    /// - `WrongVersion`: The snapshot was saved with another version of the library
+    /// - `WrongRobotCount`: `robots` doesn't have as many robots as the snapshot
+    /// - `RobotState`: A robot refused its saved state
+    pub(crate) fn restore(
+        self,
+        mut robots: Vec<Box<dyn Runnable>>,
+        config: RunnerConfig,
+    ) -> Result<Runner, SnapshotError> {
+        if self.version != SNAPSHOT_VERSION {
+            return Err(SnapshotError::WrongVersion(self.version));
+        }
+        if self.robots.len() != robots.len() {
+            return Err(SnapshotError::WrongRobotCount {
+                expected: self.robots.len(),
+                found: robots.len(),
+            });
+        }
+
+        let mut positions = Vec::with_capacity(robots.len());
+        let mut scores = Vec::with_capacity(robots.len());
+        let mut discovered = Vec::with_capacity(robots.len());
+        let mut energy_spent = Vec::with_capacity(robots.len());
+        let mut out_of_energy_ticks = Vec::with_capacity(robots.len());
+        for (robot, snapshot) in robots.iter_mut().zip(self.robots) {
+            if let (Some(robot_state), Some(state)) = (robot.get_save_state_mut(), snapshot.state) {
+                robot_state.restore_state(&state)?;
+            }
+
+            positions.push((snapshot.coordinate.get_row(), snapshot.coordinate.get_col()));
+            scores.push(snapshot.score);
+            discovered.push(snapshot.discovered.into_iter().collect::<HashSet<_>>());
+            energy_spent.push(snapshot.energy_spent);
+            out_of_energy_ticks.push(snapshot.out_of_energy_ticks);
+
+            *robot.get_energy_mut() = snapshot.energy;
+            *robot.get_coordinate_mut() = snapshot.coordinate;
+            *robot.get_backpack_mut() = snapshot.backpack;
+        }
+
+        let world = World {
+            dimension: self.map.len(),
+            map: self.map,
+            discoverable: self.discoverable,
+            environmental_conditions: self.environmental_conditions,
+            score_counter: ScoreCounter {
+                score: RefCell::new(self.score),
+                max_score: self.max_score,
+                score_table: self.score_table,
+            },
+            robots: RobotStates {
+                active: 0,
+                positions,
+                scores,
+                turn_start_score: self.score,
+                discovered: RefCell::new(discovered),
+            },
+        };
+
+        Ok(Runner {
+            robots,
+            world,
+            config,
+            ticks: self.ticks,
+            energy_spent,
+            out_of_energy_ticks,
+            outcome: self.outcome,
+            observers: vec![],
+        })
+    }
+}
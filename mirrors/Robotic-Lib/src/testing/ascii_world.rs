@@ -0,0 +1,273 @@
+use std::collections::HashMap;
+use std::fmt;
+use std::fmt::{Display, Formatter};
+use std::iter::Peekable;
+use std::str::Chars;
+
+use crate::world::environmental_conditions::{EnvironmentalConditions, WeatherType};
+use crate::world::tile::{Content, Tile, TileType};
+use crate::world::world_generator::Generator;
+
+/// A `Generator` that always gives the world written in a text map.
+///
+/// Every line of the text is a row of the map, and every cell is a word separated by spaces:
+/// `<tile type>[<content>[<amount>]][^<elevation>][@]`
+///
+/// | Tile type | Letter |  | Content | Letter |
+/// |-----------|--------|--|---------|--------|
+/// | DeepWater | `D` |  | Rock | `r` |
+/// | ShallowWater | `W` |  | Tree | `t` |
+/// | Sand | `S` |  | Garbage | `g` |
+/// | Grass | `G` |  | Fire | `f` |
+/// | Street | `R` |  | Coin | `c` |
+/// | Hill | `H` |  | Bin | `b` |
+/// | Mountain | `M` |  | Crate | `x` |
+/// | Snow | `N` |  | Bank | `k` |
+/// | Lava | `L` |  | Water | `w` |
+/// | Teleport | `T` |  | Market | `m` |
+/// | Wall | `X` |  | Fish | `h` |
+/// | | |  | Building | `y` |
+/// | | |  | Bush | `u` |
+/// | | |  | JollyBlock | `j` |
+/// | | |  | Scarecrow | `s` |
+///
+/// The amount defaults to 1, for Bin, Crate and Bank it is the end of the range, which starts at 0.
+/// `@` marks the spawn point, (0, 0) if there is none. Empty lines are ignored.
+///
+/// The weather is always sunny and the day starts at noon, unless it's changed with `with_weather` or
+/// `with_environmental_conditions`.
+///
+/// # Example
+/// ```rust
+/// use robotics_lib::testing::ascii_world::AsciiWorld;
+/// use robotics_lib::world::environmental_conditions::WeatherType::Rainy;
+///
+/// let world = AsciiWorld::parse(
+///     "
+///     G    Gr3  H^2
+///     W    R@   Mt5
+///     Xk10 G    T
+///     ",
+/// )
+/// .unwrap()
+/// .with_weather(&[Rainy]);
+/// ```
+#[derive(Debug, Clone)]
+pub struct AsciiWorld {
+    map: Vec<Vec<Tile>>,
+    spawn: (usize, usize),
+    environmental_conditions: EnvironmentalConditions,
+    max_score: f32,
+    score_table: Option<HashMap<Content, f32>>,
+}
+
+/// It contains all the errors that can happen while parsing a text map
+///
+/// # Variants
+/// - `Empty`: The text has no cell
+/// - `NotSquare { row }`: The row doesn't have as many cells as the map has rows
+/// - `InvalidCell { row, col, cell }`: The cell isn't written as `<tile type>[<content>[<amount>]][^<elevation>][@]`
+/// - `MultipleSpawns`: More than one cell is marked with `@`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AsciiMapError {
+    Empty,
+    NotSquare {
+        row: usize,
+    },
+    InvalidCell {
+        row: usize,
+        col: usize,
+        cell: String,
+    },
+    MultipleSpawns,
+}
+
+impl Display for AsciiMapError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            | AsciiMapError::Empty => write!(f, "The map is empty"),
+            | AsciiMapError::NotSquare { row } => write!(f, "Row {} doesn't make the map a square", row),
+            | AsciiMapError::InvalidCell { row, col, cell } => {
+                write!(f, "Invalid cell {:?} at ({}, {})", cell, row, col)
+            }
+            | AsciiMapError::MultipleSpawns => write!(f, "More than one spawn point"),
+        }
+    }
+}
+
+impl AsciiWorld {
+    /// Parses a text map, see `AsciiWorld` for the format
+    ///
+    /// # Errors
+    /// See `AsciiMapError`
+    pub fn parse(text: &str) -> Result<AsciiWorld, AsciiMapError> {
+        let mut map = vec![];
+        let mut spawn = None;
+
+        for line in text.lines().filter(|line| !line.trim().is_empty()) {
+            let row = map.len();
+            let mut tiles = vec![];
+            for (col, cell) in line.split_whitespace().enumerate() {
+                let invalid = || AsciiMapError::InvalidCell {
+                    row,
+                    col,
+                    cell: cell.to_string(),
+                };
+                let (tile, is_spawn) = parse_cell(cell).ok_or_else(invalid)?;
+                if is_spawn && spawn.replace((row, col)).is_some() {
+                    return Err(AsciiMapError::MultipleSpawns);
+                }
+                tiles.push(tile);
+            }
+            map.push(tiles);
+        }
+
+        if map.is_empty() {
+            return Err(AsciiMapError::Empty);
+        }
+        if let Some(row) = map.iter().position(|tiles| tiles.len() != map.len()) {
+            return Err(AsciiMapError::NotSquare { row });
+        }
+
+        Ok(AsciiWorld {
+            map,
+            spawn: spawn.unwrap_or((0, 0)),
+            environmental_conditions: EnvironmentalConditions::new(&[WeatherType::Sunny], 15, 12).unwrap(),
+            max_score: 1.0,
+            score_table: None,
+        })
+    }
+
+    /// Changes the weather forecast, the day still starts at noon
+    ///
+    /// # Panics
+    /// If the forecast is empty
+    pub fn with_weather(mut self, weather_forecast: &[WeatherType]) -> Self {
+        self.environmental_conditions = EnvironmentalConditions::new(weather_forecast, 15, 12).unwrap();
+        self
+    }
+
+    /// Changes the weather forecast and the time of the day
+    pub fn with_environmental_conditions(mut self, environmental_conditions: EnvironmentalConditions) -> Self {
+        self.environmental_conditions = environmental_conditions;
+        self
+    }
+
+    /// Changes the max score of the world (default: 1.0)
+    pub fn with_max_score(mut self, max_score: f32) -> Self {
+        self.max_score = max_score;
+        self
+    }
+
+    /// Changes the score table of the world (default: the table of `ScoreCounter`)
+    pub fn with_score_table(mut self, score_table: HashMap<Content, f32>) -> Self {
+        self.score_table = Some(score_table);
+        self
+    }
+
+    ///Returns the parsed map.
+    pub fn get_map(&self) -> &Vec<Vec<Tile>> {
+        &self.map
+    }
+
+    ///Returns the spawn point.
+    pub fn get_spawn(&self) -> (usize, usize) {
+        self.spawn
+    }
+}
+
+impl Generator for AsciiWorld {
+    fn gen(
+        &mut self,
+    ) -> (
+        Vec<Vec<Tile>>,
+        (usize, usize),
+        EnvironmentalConditions,
+        f32,
+        Option<HashMap<Content, f32>>,
+    ) {
+        (
+            self.map.clone(),
+            self.spawn,
+            self.environmental_conditions.clone(),
+            self.max_score,
+            self.score_table.clone(),
+        )
+    }
+}
+
+/// # Returns
+/// The tile of the cell and if it's the spawn point, `None` if the cell is invalid
+fn parse_cell(cell: &str) -> Option<(Tile, bool)> {
+    let mut chars = cell.chars().peekable();
+
+    let tile_type = match chars.next()? {
+        | 'D' => TileType::DeepWater,
+        | 'W' => TileType::ShallowWater,
+        | 'S' => TileType::Sand,
+        | 'G' => TileType::Grass,
+        | 'R' => TileType::Street,
+        | 'H' => TileType::Hill,
+        | 'M' => TileType::Mountain,
+        | 'N' => TileType::Snow,
+        | 'L' => TileType::Lava,
+        | 'T' => TileType::Teleport(false),
+        | 'X' => TileType::Wall,
+        | _ => return None,
+    };
+
+    let content = match chars.next_if(char::is_ascii_lowercase) {
+        | None => Content::None,
+        | Some(letter) => {
+            let amount = number(&mut chars).unwrap_or(1);
+            match letter {
+                | 'r' => Content::Rock(amount),
+                | 't' => Content::Tree(amount),
+                | 'g' => Content::Garbage(amount),
+                | 'f' => Content::Fire,
+                | 'c' => Content::Coin(amount),
+                | 'b' => Content::Bin(0..amount),
+                | 'x' => Content::Crate(0..amount),
+                | 'k' => Content::Bank(0..amount),
+                | 'w' => Content::Water(amount),
+                | 'm' => Content::Market(amount),
+                | 'h' => Content::Fish(amount),
+                | 'y' => Content::Building,
+                | 'u' => Content::Bush(amount),
+                | 'j' => Content::JollyBlock(amount),
+                | 's' => Content::Scarecrow,
+                | _ => return None,
+            }
+        }
+    };
+
+    let elevation = match chars.next_if_eq(&'^') {
+        | None => 0,
+        | Some(_) => number(&mut chars)?,
+    };
+    let is_spawn = chars.next_if_eq(&'@').is_some();
+
+    // nothing else can follow
+    if chars.next().is_some() {
+        return None;
+    }
+
+    Some((
+        Tile {
+            tile_type,
+            content,
+            elevation,
+        },
+        is_spawn,
+    ))
+}
+
+/// # Returns
+/// The number at the start of the characters, `None` if there is none
+fn number(chars: &mut Peekable<Chars>) -> Option<usize> {
+    let mut digits = String::new();
+    while let Some(digit) = chars.next_if(char::is_ascii_digit) {
+        digits.push(digit);
+    }
+    digits.parse().ok()
+}
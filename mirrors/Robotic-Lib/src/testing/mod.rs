@@ -0,0 +1,7 @@
+//! Helpers to write scenario tests for robots and tools in a few lines.
+//!
+//! - `ascii_world`: a `Generator` built from a compact text map
+//! - `scripted_robot`: a robot whose `process_tick` runs a closure
+
+pub mod ascii_world;
+pub mod scripted_robot;
@@ -0,0 +1,72 @@
+use crate::event::events::Event;
+use crate::runner::{MaybeSend, Robot, RobotState, Runnable};
+use crate::world::World;
+
+#[cfg(feature = "send")]
+type Script = Box<dyn FnMut(&mut ScriptedRobot, &mut World) + Send>;
+#[cfg(not(feature = "send"))]
+type Script = Box<dyn FnMut(&mut ScriptedRobot, &mut World)>;
+
+/// A robot whose `process_tick` runs a closure, so that a test doesn't have to implement `Runnable`.
+///
+/// The closure receives the robot itself, which can be given to the interfaces, and the world.
+/// Every event received by the robot is kept, see `get_events`.
+///
+/// # Example
+/// ```rust
+/// use robotics_lib::interface::{go, Direction};
+/// use robotics_lib::runner::Runner;
+/// use robotics_lib::testing::ascii_world::AsciiWorld;
+/// use robotics_lib::testing::scripted_robot::ScriptedRobot;
+///
+/// let mut world = AsciiWorld::parse(
+///     "
+///     G@ G
+///     G  G
+///     ",
+/// )
+/// .unwrap();
+/// let robot = ScriptedRobot::new(|robot, world| {
+///     let _ = go(robot, world, Direction::Right);
+/// });
+///
+/// let mut runner = Runner::new(Box::new(robot), &mut world).unwrap();
+/// runner.game_tick().unwrap();
+/// assert_eq!(runner.get_robot().get_coordinate().get_col(), 1);
+/// ```
+#[derive(RobotState)]
+pub struct ScriptedRobot {
+    #[robot]
+    robot: Robot,
+    script: Option<Script>,
+    events: Vec<Event>,
+}
+
+impl ScriptedRobot {
+    /// Creates a robot that runs `script` at every tick
+    pub fn new(script: impl FnMut(&mut ScriptedRobot, &mut World) + MaybeSend + 'static) -> Self {
+        ScriptedRobot {
+            robot: Robot::new(),
+            script: Some(Box::new(script)),
+            events: vec![],
+        }
+    }
+
+    ///Returns the events received so far, the oldest first.
+    pub fn get_events(&self) -> &Vec<Event> {
+        &self.events
+    }
+}
+
+impl Runnable for ScriptedRobot {
+    fn process_tick(&mut self, world: &mut World) {
+        // the script is taken out while it runs, as it needs the robot
+        if let Some(mut script) = self.script.take() {
+            script(self, world);
+            self.script = Some(script);
+        }
+    }
+    fn handle_event(&mut self, event: Event) {
+        self.events.push(event);
+    }
+}
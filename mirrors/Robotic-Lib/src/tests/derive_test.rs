@@ -0,0 +1,66 @@
+use super::*;
+
+// Keeps the robot between other fields
+#[derive(RobotState)]
+struct FieldRobot {
+    steps: usize,
+    #[robot]
+    robot: Robot,
+    name: &'static str,
+}
+
+// Wraps another robot, the accessors go through its own `RobotState`
+#[derive(RobotState)]
+struct WrapperRobot<T: RobotState>(usize, #[robot] T);
+
+impl<T: Runnable> Runnable for WrapperRobot<T> {
+    fn process_tick(&mut self, world: &mut World) {
+        self.0 += 1;
+        self.1.process_tick(world);
+    }
+    fn handle_event(&mut self, event: Event) {
+        self.1.handle_event(event);
+    }
+}
+
+#[test]
+fn derive_named_field_test() {
+    let mut robot = FieldRobot {
+        steps: 0,
+        robot: Robot::new(),
+        name: "field",
+    };
+
+    robot.get_energy_mut().consume_energy(10).unwrap();
+    *robot.get_coordinate_mut() = Coordinate::new(3, 4);
+    robot.get_backpack_mut().size = 5;
+
+    assert_eq!(robot.get_energy().get_energy_level(), MAX_ENERGY_LEVEL - 10);
+    assert_eq!(robot.robot.energy.get_energy_level(), MAX_ENERGY_LEVEL - 10);
+    assert_eq!(robot.get_coordinate(), &Coordinate::new(3, 4));
+    assert_eq!(robot.robot.coordinate, Coordinate::new(3, 4));
+    assert_eq!(robot.get_backpack().get_size(), 5);
+    assert_eq!(robot.robot.backpack.get_size(), 5);
+    assert_eq!((robot.steps, robot.name), (0, "field"));
+}
+
+#[test]
+fn derive_generic_field_test() {
+    let mut robot = WrapperRobot(0, TestRobot(Robot::new()));
+
+    *robot.get_coordinate_mut() = Coordinate::new(1, 2);
+    robot.get_backpack_mut().size = 7;
+
+    assert_eq!((robot.1).0.coordinate, Coordinate::new(1, 2));
+    assert_eq!((robot.1).0.backpack.get_size(), 7);
+    assert_eq!(robot.0, 0);
+}
+
+#[test]
+fn derive_runnable_test() {
+    let mut dummy_world = TestWorld::init(10);
+    let mut run = Runner::new(Box::new(WrapperRobot(0, TestRobot(Robot::new()))), &mut dummy_world).unwrap();
+
+    assert_eq!(run.game_tick(), Ok(()));
+    assert_eq!(run.get_robot().get_backpack().get_size(), 20);
+}
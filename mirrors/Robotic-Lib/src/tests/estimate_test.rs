@@ -0,0 +1,265 @@
+use super::*;
+use crate::interface::{
+    craft, estimate_craft, estimate_destroy, estimate_go, estimate_put, estimate_teleport, teleport,
+};
+
+fn energy_used(robot: &TestRobot) -> usize {
+    MAX_ENERGY_LEVEL - robot.get_energy().get_energy_level()
+}
+
+#[test]
+fn estimate_go_test() {
+    let mut map = generate_map_of_type_and_content(Hill, Content::None, 3);
+    map[0][1].elevation = 2;
+    map[1][0].tile_type = Wall;
+    let mut world = world_of(map);
+    let mut robot = robot_at(0, 0, vec![]);
+
+    assert_eq!(estimate_go(&robot, &world, Down), Err(CannotWalk));
+    assert_eq!(estimate_go(&robot, &world, Direction::Up), Err(OutOfBounds));
+
+    let estimate = estimate_go(&robot, &world, Right);
+    assert_eq!(robot.get_coordinate(), &Coordinate::new(0, 0));
+    assert!(go(&mut robot, &mut world, Right).is_ok());
+    assert_eq!(estimate, Ok(energy_used(&robot)));
+
+    *robot.get_energy_mut() = Energy::new(0);
+    assert_eq!(estimate_go(&robot, &world, Direction::Left), Err(NotEnoughEnergy));
+}
+
+#[test]
+fn estimate_teleport_test() {
+    let mut map = generate_map_of_type_and_content(Grass, Content::None, 3);
+    map[0][0].tile_type = Teleport(true);
+    map[2][2].tile_type = Teleport(true);
+    let mut world = world_of(map);
+    let mut robot = robot_at(0, 0, vec![]);
+
+    assert_eq!(estimate_teleport(&robot, &world, (1, 1)), Err(OperationNotAllowed));
+    let estimate = estimate_teleport(&robot, &world, (2, 2));
+    assert!(teleport(&mut robot, &mut world, (2, 2)).is_ok());
+    assert_eq!(estimate, Ok(energy_used(&robot)));
+}
+
+#[test]
+fn estimate_destroy_test() {
+    let mut map = generate_map_of_type_and_content(Grass, Content::None, 3);
+    map[0][1].content = Tree(2);
+    let mut world = world_of(map);
+    let mut robot = robot_at(0, 0, vec![]);
+
+    assert_eq!(estimate_destroy(&robot, &world, Down), Err(NoContent));
+
+    let estimate = estimate_destroy(&robot, &world, Right);
+    assert_eq!(world.map[0][1].content, Tree(2));
+    assert_eq!(world.score_counter.get_score(), 0.);
+    assert!(robot.get_backpack().get_contents().is_empty());
+    assert_eq!(robot.get_energy().get_energy_level(), MAX_ENERGY_LEVEL);
+
+    assert_eq!(destroy(&mut robot, &mut world, Right), Ok(2));
+    assert_eq!(estimate, Ok(energy_used(&robot)));
+}
+
+#[test]
+fn estimate_put_test() {
+    let mut map = generate_map_of_type_and_content(Street, Content::None, 3);
+    map[1][0].content = Bank(0..10);
+    let mut world = world_of(map);
+    let mut robot = robot_at(0, 0, vec![(Coin(0), 5)]);
+
+    assert_eq!(estimate_put(&robot, &world, Garbage(0), 1, Down), Err(WrongContentUsed));
+
+    let estimate = estimate_put(&robot, &world, Coin(0), 5, Down);
+    assert_eq!(world.map[1][0].content, Bank(0..10));
+    assert_eq!(robot.get_backpack().get_contents().get(&Coin(0)), Some(&5));
+
+    assert_eq!(put(&mut robot, &mut world, Coin(0), 5, Down), Ok(5));
+    assert_eq!(estimate, Ok(energy_used(&robot)));
+}
+
+#[test]
+fn estimate_craft_test() {
+    let mut robot = robot_at(0, 0, vec![(Rock(0), 3)]);
+
+    assert_eq!(estimate_craft(&robot, Tree(0)), Err(NotCraftable));
+
+    let estimate = estimate_craft(&robot, Garbage(0));
+    assert_eq!(robot.get_backpack().get_contents().get(&Rock(0)), Some(&3));
+    assert_eq!(craft(&mut robot, Garbage(0)), Ok(Garbage(0)));
+    assert_eq!(estimate, Ok(energy_used(&robot)));
+}
+
+/// Plays the action after estimating it, the estimate must be its energy or its error
+fn assert_estimate<T>(estimate: Result<usize, LibError>, robot: &TestRobot, result: Result<T, LibError>) {
+    match result {
+        | Ok(_) => assert_eq!(estimate, Ok(energy_used(robot))),
+        | Err(error) => assert_eq!(estimate, Err(error)),
+    }
+}
+
+#[test]
+fn estimate_destroy_cases_test() {
+    let cases = vec![
+        (ShallowWater, Content::None, vec![]),
+        (Grass, Fire, vec![]),
+        (Grass, Rock(3), vec![(Tree(0), 19)]),
+        (Grass, Bank(0..5), vec![]),
+        (DeepWater, Fish(2), vec![]),
+    ];
+    for (tile_type, content, backpack) in cases {
+        let mut map = generate_map_of_type_and_content(Grass, Content::None, 3);
+        map[0][1] = Tile {
+            tile_type,
+            content,
+            elevation: 0,
+        };
+        let mut world = world_of(map);
+        let mut robot = robot_at(0, 0, backpack);
+
+        let estimate = estimate_destroy(&robot, &world, Right);
+        let result = destroy(&mut robot, &mut world, Right);
+        assert_estimate(estimate, &robot, result);
+    }
+}
+
+#[test]
+fn estimate_put_cases_test() {
+    let cases = vec![
+        (ShallowWater, Content::None, Rock(0), 2, vec![(Rock(0), 2)]),
+        (DeepWater, Content::None, Rock(0), 3, vec![(Rock(0), 1)]),
+        (Lava, Content::None, Rock(0), 3, vec![(Rock(0), 3)]),
+        (Grass, Tree(1), Rock(0), 1, vec![(Rock(0), 1)]),
+        (Grass, Fire, Water(0), 1, vec![(Water(0), 3)]),
+        (Grass, Tree(2), Fire, 1, vec![(Fire, 1)]),
+        (Street, Rock(2), Rock(0), 5, vec![(Rock(0), 5)]),
+        (Street, Content::None, Rock(0), 2, vec![]),
+        (Street, Market(2), Tree(0), 3, vec![(Tree(0), 3)]),
+        (Street, Market(2), Garbage(0), 1, vec![(Garbage(0), 1)]),
+        (Street, Market(2), Fish(0), 3, vec![(Fish(0), 3), (Rock(0), 10)]),
+        (Street, Bin(0..2), Garbage(0), 4, vec![(Garbage(0), 4)]),
+    ];
+    for (tile_type, content, content_in, quantity, backpack) in cases {
+        let mut map = generate_map_of_type_and_content(Grass, Content::None, 3);
+        map[0][1] = Tile {
+            tile_type,
+            content,
+            elevation: 0,
+        };
+        let mut world = world_of(map);
+        let mut robot = robot_at(0, 0, backpack);
+
+        let estimate = estimate_put(&robot, &world, content_in.clone(), quantity, Right);
+        let result = put(&mut robot, &mut world, content_in, quantity, Right);
+        assert_estimate(estimate, &robot, result);
+    }
+}
+
+/// One case for every arm of `put` and for each error it can return, with the robot's energy
+#[test]
+fn estimate_put_branches_test() {
+    let full = MAX_ENERGY_LEVEL;
+    let cases = vec![
+        // content that doesn't fit the target
+        (Grass, Content::None, Content::None, 1, vec![], full),
+        // Coin into Bank, Garbage into Bin, Tree into Crate
+        (Street, Bank(0..10), Coin(0), 5, vec![(Coin(0), 5)], full),
+        (Street, Bank(0..10), Coin(0), 5, vec![(Coin(0), 5)], 1),
+        (Street, Bank(0..10), Coin(0), 5, vec![(Coin(0), 0)], full),
+        (Street, Bin(0..2), Garbage(0), 4, vec![(Garbage(0), 4)], full),
+        (Street, Bin(0..10), Garbage(0), 4, vec![(Garbage(0), 4)], 0),
+        (Street, Crate(3..10), Tree(0), 9, vec![(Tree(0), 9)], full),
+        (Street, Crate(3..10), Tree(0), 9, vec![(Tree(0), 0)], full),
+        // Fire into Tree or an empty tile
+        (Grass, Tree(2), Fire, 1, vec![(Fire, 1)], full),
+        (Grass, Content::None, Fire, 1, vec![(Fire, 1)], 0),
+        (Grass, Content::None, Fire, 1, vec![(Fire, 0)], full),
+        (ShallowWater, Content::None, Fire, 1, vec![(Fire, 1)], full),
+        // Market
+        (Street, Market(2), Tree(0), 3, vec![(Tree(0), 3)], full),
+        (Street, Market(0), Tree(0), 3, vec![(Tree(0), 3)], full),
+        (Street, Market(2), Garbage(0), 1, vec![(Garbage(0), 1)], full),
+        (Street, Market(2), Fish(0), 3, vec![(Fish(0), 3), (Rock(0), 10)], full),
+        // Rock into Grass, Hill, Sand and Snow
+        (Hill, Content::None, Rock(0), 1, vec![(Rock(0), 1)], full),
+        (Sand, Content::None, Rock(0), 1, vec![(Rock(0), 1)], 0),
+        (Snow, Content::None, Rock(0), 1, vec![(Rock(0), 0)], full),
+        (Grass, Tree(1), Rock(0), 1, vec![(Rock(0), 1)], full),
+        // Rock into ShallowWater, DeepWater and Lava
+        (ShallowWater, Content::None, Rock(0), 2, vec![(Rock(0), 2)], full),
+        (ShallowWater, Content::None, Rock(0), 2, vec![(Rock(0), 1)], full),
+        (ShallowWater, Content::None, Rock(0), 2, vec![(Rock(0), 2)], 1),
+        (ShallowWater, Fish(1), Rock(0), 2, vec![(Rock(0), 2)], full),
+        (DeepWater, Content::None, Rock(0), 3, vec![(Rock(0), 3)], full),
+        (DeepWater, Content::None, Rock(0), 3, vec![(Rock(0), 1)], full),
+        (DeepWater, Content::None, Rock(0), 3, vec![(Rock(0), 3)], 5),
+        (Lava, Content::None, Rock(0), 3, vec![(Rock(0), 3)], full),
+        (Lava, Content::None, Rock(0), 3, vec![(Rock(0), 2)], full),
+        (Lava, Content::None, Rock(0), 3, vec![(Rock(0), 3)], 8),
+        // Rock into an empty tile
+        (Street, Content::None, Rock(0), 2, vec![(Rock(0), 2)], full),
+        (Street, Content::None, Rock(0), 2, vec![(Rock(0), 2)], 1),
+        (Street, Content::None, Rock(0), 2, vec![], full),
+        // Water into Fire, anything else into Fire
+        (Grass, Fire, Water(0), 1, vec![(Water(0), 3)], full),
+        (Grass, Fire, Water(0), 1, vec![(Water(0), 3)], 0),
+        (Grass, Fire, Water(0), 1, vec![(Water(0), 0)], full),
+        (Grass, Fire, Tree(0), 2, vec![(Tree(0), 2)], full),
+        (Grass, Fire, Tree(0), 2, vec![(Tree(0), 2)], 0),
+        // anything else into an empty tile
+        (Grass, Content::None, Tree(0), 2, vec![(Tree(0), 2)], full),
+        (Grass, Content::None, Tree(0), 2, vec![(Tree(0), 2)], 1),
+        (Lava, Content::None, Tree(0), 2, vec![(Tree(0), 2)], full),
+        // the same content as the tile's
+        (Street, Rock(2), Rock(0), 5, vec![(Rock(0), 5)], full),
+        (Street, Rock(2), Rock(0), 5, vec![(Rock(0), 5)], 1),
+        (Street, Rock(2), Tree(0), 1, vec![(Tree(0), 1)], full),
+        (
+            Street,
+            Content::Building,
+            Content::Building,
+            1,
+            vec![(Content::Building, 1)],
+            full,
+        ),
+    ];
+    for (tile_type, content, content_in, quantity, backpack, energy) in cases {
+        let mut map = generate_map_of_type_and_content(Grass, Content::None, 3);
+        map[0][1] = Tile {
+            tile_type,
+            content: content.clone(),
+            elevation: 0,
+        };
+        let mut world = world_of(map);
+        let mut robot = robot_at(0, 0, backpack);
+        *robot.get_energy_mut() = Energy::new(energy);
+
+        let estimate = estimate_put(&robot, &world, content_in.clone(), quantity, Right);
+        let result = put(&mut robot, &mut world, content_in.clone(), quantity, Right);
+        let spent = energy - robot.get_energy().get_energy_level();
+        match result {
+            | Ok(_) => assert_eq!(estimate, Ok(spent), "{content_in} into {content:?} on {tile_type:?}"),
+            | Err(error) => assert_eq!(estimate, Err(error), "{content_in} into {content:?} on {tile_type:?}"),
+        }
+    }
+}
+
+#[test]
+fn estimate_put_mountain_test() {
+    let mut map = generate_map_of_type_and_content(Grass, Content::None, 3);
+    map[0][1].tile_type = Mountain;
+    map[1][0].tile_type = Mountain;
+    let mut world = world_of(map);
+    let mut robot = robot_at(0, 0, vec![]);
+
+    // the estimate is the most it can cost, whatever amount of rocks the mountain gives
+    let estimate = estimate_put(&robot, &world, Content::None, 0, Right).unwrap();
+    assert_eq!(estimate_put(&robot, &world, Content::None, 0, Right), Ok(estimate));
+    assert!(put(&mut robot, &mut world, Content::None, 0, Right).is_ok());
+    assert!(energy_used(&robot) <= estimate);
+
+    *robot.get_energy_mut() = Energy::new(estimate - 1);
+    assert_eq!(
+        estimate_put(&robot, &world, Content::None, 0, Down),
+        Err(NotEnoughEnergy)
+    );
+}
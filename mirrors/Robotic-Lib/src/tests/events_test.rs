@@ -0,0 +1,150 @@
+use std::sync::{Arc, Mutex};
+
+use super::*;
+use crate::event::events::ScoreReason;
+use crate::interface::{craft, discover_tiles, teleport};
+use crate::world::score::ScoreCounter;
+
+// Keeps every event it receives
+#[derive(RobotState)]
+struct EventRobot(#[robot] Robot, Arc<Mutex<Vec<Event>>>);
+
+impl EventRobot {
+    fn new(robot: Robot) -> Self {
+        EventRobot(robot, Arc::new(Mutex::new(vec![])))
+    }
+
+    fn events(&self) -> Vec<Event> {
+        self.1.lock().unwrap().clone()
+    }
+}
+
+impl Runnable for EventRobot {
+    fn process_tick(&mut self, _world: &mut World) {}
+    fn handle_event(&mut self, event: Event) {
+        self.1.lock().unwrap().push(event);
+    }
+}
+
+// A world of `tile_type` with `content` everywhere but in (1, 1), where there is `target`
+fn world_with_target(tile_type: TileType, content: Content, target: Content) -> World {
+    let mut map = generate_map_of_type_and_content(tile_type, content, 3);
+    map[1][1].content = target;
+    let score_counter = ScoreCounter::new(1.0, &map, None);
+    World {
+        map,
+        dimension: 3,
+        discoverable: 3,
+        environmental_conditions: generate_sunny_weather(),
+        score_counter,
+        robots: Default::default(),
+    }
+}
+
+fn robot_with_backpack(contents: Vec<(Content, usize)>, coordinate: (usize, usize)) -> EventRobot {
+    EventRobot::new(Robot {
+        energy: Energy::new(MAX_ENERGY_LEVEL),
+        coordinate: Coordinate::new(coordinate.0, coordinate.1),
+        backpack: BackPack {
+            size: 20,
+            contents: contents.into_iter().collect(),
+        },
+    })
+}
+
+#[test]
+fn deposit_events_test() {
+    let mut world = world_with_target(Street, Content::None, Bank(0..10));
+    let mut robot = robot_with_backpack(vec![(Coin(0), 5)], (0, 1));
+
+    assert_eq!(put(&mut robot, &mut world, Coin(0), 3, Down), Ok(3));
+
+    let events = robot.events();
+    assert!(events.contains(&Event::Deposited {
+        content: Coin(0),
+        quantity: 3,
+        container: Bank(0..0),
+    }));
+    assert!(events.iter().any(|event| matches!(
+        event,
+        Event::ScoreIncreased { delta, reason: ScoreReason::Disposed(Bank(_)) } if *delta > 0.
+    )));
+}
+
+#[test]
+fn destroy_score_event_test() {
+    let mut world = world_with_target(Sand, Content::None, Rock(2));
+    let mut robot = robot_with_backpack(vec![], (0, 1));
+
+    assert_eq!(destroy(&mut robot, &mut world, Down), Ok(2));
+    assert!(robot.events().iter().any(|event| matches!(
+        event,
+        Event::ScoreIncreased {
+            reason: ScoreReason::Destroyed(Rock(0)),
+            ..
+        }
+    )));
+}
+
+#[test]
+fn sold_event_test() {
+    let mut world = world_with_target(Grass, Content::None, Market(3));
+    let mut robot = robot_with_backpack(vec![(Tree(0), 4)], (0, 1));
+
+    assert_eq!(put(&mut robot, &mut world, Tree(0), 4, Down), Ok(8));
+    assert!(robot.events().contains(&Event::Sold {
+        content: Tree(0),
+        quantity: 4,
+        coins: 8,
+    }));
+}
+
+#[test]
+fn crafted_event_test() {
+    let mut robot = robot_with_backpack(vec![(Rock(0), 3)], (0, 0));
+
+    assert_eq!(craft(&mut robot, Garbage(0)), Ok(Garbage(0)));
+    assert_eq!(robot.events().last(), Some(&Event::Crafted(Garbage(0))));
+}
+
+#[test]
+fn teleported_and_discovered_events_test() {
+    let mut world = world_with_target(Teleport(true), Content::None, Content::None);
+    let mut robot = robot_with_backpack(vec![], (0, 0));
+
+    assert!(teleport(&mut robot, &mut world, (2, 2)).is_ok());
+    assert_eq!(
+        robot.events().last(),
+        Some(&Event::Teleported(world.map[2][2].clone(), (2, 2)))
+    );
+
+    assert!(discover_tiles(&mut robot, &mut world, &[(1, 1), (5, 5)]).is_ok());
+    assert_eq!(robot.events().last(), Some(&Event::TilesDiscovered(vec![(1, 1)])));
+}
+
+#[test]
+fn weather_changed_event_test() {
+    let robot = EventRobot::new(Robot::new());
+    let events = robot.1.clone();
+    let mut dummy_world = TestWorld::init(3);
+    let mut run = Runner::new(Box::new(robot), &mut dummy_world).unwrap();
+
+    // TestWorld starts a sunny day at noon, 15 minutes every tick
+    for _ in 0..48 {
+        assert_eq!(run.game_tick(), Ok(()));
+    }
+
+    let events = events.lock().unwrap();
+    let day_changed = events
+        .iter()
+        .position(|event| matches!(event, Event::DayChanged(_)))
+        .unwrap();
+    assert_eq!(events[day_changed + 1], Event::WeatherChanged(Rainy));
+    assert_eq!(
+        events
+            .iter()
+            .filter(|event| matches!(event, Event::WeatherChanged(_)))
+            .count(),
+        1
+    );
+}
@@ -1,5 +1,5 @@
 use crate::{
-    interface::{craft, discover_tiles, look_at_sky, teleport, where_am_i},
+    interface::{craft, discover_tiles, look_at_sky, robot_map, teleport, where_am_i},
     world::score::ScoreCounter,
 };
 
@@ -96,6 +96,7 @@ fn go_interface_test() {
         discoverable: INTERFACE_WORLD_SIZE / 10 + 1,
         environmental_conditions: generate_sunny_weather(),
         score_counter,
+        robots: Default::default(),
     };
 
     let result: Result<(Vec<Vec<Option<Tile>>>, (usize, usize)), LibError> =
@@ -273,6 +274,7 @@ fn teleport_interface_test() {
         discoverable: INTERFACE_WORLD_SIZE / 10 + 1,
         environmental_conditions: generate_sunny_weather(),
         score_counter,
+        robots: Default::default(),
     };
 
     // check if the attribute is set to true it will teleport like there is no tomorrow
@@ -429,6 +431,7 @@ fn return_world_for_destroy_test(backpack_size: usize) -> (World, TestRobot) {
             discoverable: 2 / 10 + 1,
             environmental_conditions: generate_sunny_weather(),
             score_counter,
+            robots: Default::default(),
         },
         robot,
     )
@@ -599,6 +602,7 @@ fn discover_tiles_interface_test_1() {
         discoverable: 20,
         environmental_conditions: generate_sunny_weather(),
         score_counter: Default::default(),
+        robots: Default::default(),
     };
 
     let to_discovery: Vec<(usize, usize)> = vec![
@@ -704,6 +708,7 @@ fn discover_tiles_interface_test_2() {
         discoverable: INTERFACE_WORLD_SIZE / 10 + 1,
         environmental_conditions: generate_sunny_weather(),
         score_counter: Default::default(),
+        robots: Default::default(),
     };
 
     let to_discovery: Vec<(usize, usize)> = vec![
@@ -733,6 +738,7 @@ fn discover_tiles_interface_test_3() {
         discoverable: INTERFACE_WORLD_SIZE / 10 + 1,
         environmental_conditions: generate_sunny_weather(),
         score_counter: Default::default(),
+        robots: Default::default(),
     };
 
     while robot.get_energy().has_enough_energy(2) {
@@ -747,6 +753,36 @@ fn discover_tiles_interface_test_3() {
     assert_eq!(discovered, Err(NotEnoughEnergy))
 }
 
+// Two worlds in the same process keep their own discovered tiles
+#[test]
+fn robot_map_is_kept_per_world() {
+    let new_world = || World {
+        map: generate_map_go_interface(),
+        dimension: INTERFACE_WORLD_SIZE,
+        discoverable: INTERFACE_WORLD_SIZE / 10 + 1,
+        environmental_conditions: generate_sunny_weather(),
+        score_counter: Default::default(),
+        robots: Default::default(),
+    };
+    let mut robot = TestRobot(Robot::new());
+    let mut discovering_world = new_world();
+    let other_world = new_world();
+
+    let _ = discover_tiles(&mut robot, &mut discovering_world, &[(2, 2)]);
+
+    let discovering_map = robot_map(&discovering_world).unwrap();
+    assert_eq!(discovering_map[2][2], Some(discovering_world.map[2][2].clone()));
+    assert_eq!(
+        discovering_map.iter().flatten().filter(|tile| tile.is_some()).count(),
+        1
+    );
+    assert!(robot_map(&other_world)
+        .unwrap()
+        .iter()
+        .flatten()
+        .all(|tile| tile.is_none()));
+}
+
 // This will test the put interface :
 /**************************************************************************
 *  MAP:
@@ -815,6 +851,7 @@ fn generate_map_robot(
             discoverable: 2 / 10 + 1,
             environmental_conditions: generate_sunny_weather(),
             score_counter,
+            robots: Default::default(),
         },
         robot,
     )
@@ -1378,6 +1415,7 @@ fn generate_map_and_robot_for_one_direction(map_size: usize, initial_position: (
             discoverable: 2 / 10 + 1,
             environmental_conditions: generate_sunny_weather(),
             score_counter,
+            robots: Default::default(),
         },
         robot,
     )
@@ -0,0 +1,171 @@
+use super::*;
+use crate::interface::middleware::{
+    Call, CallLog, CallRecord, CallStats, FaultInjection, InterfaceContext, Middleware, Policy,
+};
+use crate::testing::ascii_world::AsciiWorld;
+
+#[test]
+fn middleware_log_test() {
+    let mut world = world_of(generate_map_of_type_and_content(Grass, Rock(1), 3));
+    let mut robot = robot_at(0, 0, vec![]);
+    let log = CallLog::new();
+    let mut interface = InterfaceContext::new().with(log.clone());
+
+    assert_eq!(interface.destroy(&mut robot, &mut world, Right), Ok(1));
+    let destroy_cost = MAX_ENERGY_LEVEL - robot.get_energy().get_energy_level();
+    assert!(interface.go(&mut robot, &mut world, Right).is_ok());
+    assert_eq!(interface.go(&mut robot, &mut world, Direction::Up), Err(OutOfBounds));
+
+    let records = log.get_records();
+    assert_eq!(records.len(), 3);
+    assert_eq!(
+        records[0],
+        CallRecord {
+            call: Call::Destroy(Right),
+            result: Ok(()),
+            energy_spent: destroy_cost,
+        }
+    );
+    assert_eq!(records[1].call, Call::Go(Right));
+    assert!(records[1].energy_spent > 0);
+    assert_eq!(records[2].result, Err(OutOfBounds));
+    assert_eq!(records[2].energy_spent, 0);
+
+    let stats = log.get_stats();
+    assert_eq!(
+        stats["go"],
+        CallStats {
+            calls: 2,
+            failures: 1,
+            energy_spent: records[1].energy_spent,
+        }
+    );
+    assert_eq!(stats["destroy"].calls, 1);
+    assert!(!stats.contains_key("put"));
+}
+
+#[test]
+fn middleware_policy_test() {
+    let mut map = generate_map_of_type_and_content(Grass, Content::None, 3);
+    map[0][0].tile_type = Teleport(true);
+    map[2][2].tile_type = Teleport(true);
+    let mut world = world_of(map);
+    let mut robot = robot_at(0, 0, vec![]);
+    let log = CallLog::new();
+    let mut interface = InterfaceContext::new()
+        .with(Policy::new(|call| !matches!(call, Call::Teleport(_))))
+        .with(log.clone());
+
+    assert_eq!(
+        interface.teleport(&mut robot, &mut world, (2, 2)),
+        Err(OperationNotAllowed)
+    );
+    assert_eq!(robot.get_coordinate(), &Coordinate::new(0, 0));
+    assert_eq!(robot.get_energy().get_energy_level(), MAX_ENERGY_LEVEL);
+    assert!(interface.go(&mut robot, &mut world, Down).is_ok());
+
+    // The refused call reaches the log that comes after the policy
+    let records = log.get_records();
+    assert_eq!(records.len(), 2);
+    assert_eq!(records[0].call, Call::Teleport((2, 2)));
+    assert_eq!(records[0].result, Err(OperationNotAllowed));
+}
+
+#[test]
+fn middleware_stateful_policy_test() {
+    let mut world = world_of(generate_map_of_type_and_content(Grass, Content::None, 3));
+    let mut robot = robot_at(0, 0, vec![]);
+    let mut steps = 0;
+    let mut interface = InterfaceContext::new().with(Policy::new(move |call| {
+        if let Call::Go(_) = call {
+            steps += 1;
+        }
+        steps <= 2
+    }));
+
+    assert!(interface.go(&mut robot, &mut world, Right).is_ok());
+    assert!(interface.go(&mut robot, &mut world, Right).is_ok());
+    assert_eq!(interface.go(&mut robot, &mut world, Down), Err(OperationNotAllowed));
+    assert_eq!(robot.get_coordinate(), &Coordinate::new(0, 2));
+}
+
+// The context is taken out of the robot for the call, as the robot is borrowed by it too
+#[derive(RobotState)]
+struct ContextRobot {
+    #[robot]
+    robot: Robot,
+    interface: InterfaceContext,
+}
+
+impl Runnable for ContextRobot {
+    fn process_tick(&mut self, world: &mut World) {
+        let mut interface = std::mem::take(&mut self.interface);
+        let _ = interface.go(self, world, Right);
+        self.interface = interface;
+    }
+    fn handle_event(&mut self, _event: Event) {}
+}
+
+#[test]
+fn middleware_robot_field_test() {
+    let log = CallLog::new();
+    let robot = ContextRobot {
+        robot: Robot::new(),
+        interface: InterfaceContext::new().with(log.clone()),
+    };
+    let mut world = AsciiWorld::parse(
+        "
+        G@ G G
+        G  G G
+        G  G G
+        ",
+    )
+    .unwrap();
+    let mut runner = Runner::new(Box::new(robot), &mut world).unwrap();
+
+    runner.game_tick().unwrap();
+    runner.game_tick().unwrap();
+    runner.game_tick().unwrap();
+
+    // The context put back after each tick keeps its middleware
+    let records = log.get_records();
+    assert_eq!(records.len(), 3);
+    assert!(records[0].result.is_ok() && records[1].result.is_ok());
+    assert_eq!(records[2].result, Err(OutOfBounds));
+    assert_eq!(runner.get_robot().get_coordinate(), &Coordinate::new(0, 2));
+}
+
+#[test]
+fn middleware_fault_injection_rate_test() {
+    let call = Call::Craft(Rock(0));
+    assert_eq!(FaultInjection::new(f64::NAN, NotEnoughEnergy).before(&call), Ok(()));
+    assert_eq!(FaultInjection::new(-1.0, NotEnoughEnergy).before(&call), Ok(()));
+    assert_eq!(
+        FaultInjection::new(2.0, NotEnoughEnergy).before(&call),
+        Err(NotEnoughEnergy)
+    );
+}
+
+#[test]
+fn middleware_fault_injection_test() {
+    let mut world = world_of(generate_map_of_type_and_content(Grass, Content::None, 3));
+    let mut robot = robot_at(0, 0, vec![]);
+
+    let mut always = InterfaceContext::new().with(FaultInjection::new(1.0, NotEnoughEnergy));
+    assert_eq!(always.go(&mut robot, &mut world, Right), Err(NotEnoughEnergy));
+    assert_eq!(robot.get_coordinate(), &Coordinate::new(0, 0));
+
+    let mut never = InterfaceContext::new().with(FaultInjection::new(0.0, NotEnoughEnergy));
+    assert!(never.go(&mut robot, &mut world, Right).is_ok());
+    assert!(never.go(&mut robot, &mut world, Direction::Left).is_ok());
+
+    // The same seed fails the same calls
+    let failures = |seed| {
+        let mut fault = FaultInjection::new(0.5, CannotWalk).with_seed(seed);
+        (0..20)
+            .map(|_| fault.before(&Call::Craft(Rock(0))).is_err())
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(failures(7), failures(7));
+    assert!(failures(7).contains(&true) && failures(7).contains(&false));
+}
@@ -10,12 +10,13 @@ use crate::interface::Direction::{Down, Right};
 use crate::interface::{debug, destroy, go, put, Tools};
 use crate::interface::{one_direction_view, Direction};
 use crate::runner::backpack::BackPack;
-use crate::runner::{Robot, Runnable, Runner};
+use crate::runner::{Robot, RobotState, Runnable, Runner};
 use crate::utils::LibError::*;
 use crate::utils::*;
 use crate::world::coordinates::Coordinate;
 use crate::world::environmental_conditions::EnvironmentalConditions;
 use crate::world::environmental_conditions::WeatherType::{Rainy, Sunny};
+use crate::world::score::ScoreCounter;
 use crate::world::tile::Content::{Bank, Bin, Coin, Crate, Fire, Fish, Garbage, Market, Rock, Tree, Water};
 use crate::world::tile::TileType::{
     DeepWater, Grass, Hill, Lava, Mountain, Sand, ShallowWater, Snow, Street, Teleport, Wall,
@@ -25,13 +26,21 @@ use crate::world::world_generator::Generator;
 use crate::world::World;
 
 mod backpack_test;
+mod derive_test;
 mod energy_tests;
+mod estimate_test;
+mod events_test;
 mod interface_tests;
+mod middleware_test;
+mod pathfinding_test;
 mod runner_test;
+mod snapshot_test;
+mod testing_test;
 mod utils_test;
 mod world_tests;
 
 // Structs and function implementation shared in more than one test
+#[derive(RobotState)]
 struct TestRobot(Robot);
 
 struct TestWorld {
@@ -81,30 +90,6 @@ impl Runnable for TestRobot {
     fn handle_event(&mut self, event: Event) {
         println!("{:?}", event);
     }
-
-    fn get_energy(&self) -> &crate::energy::Energy {
-        &self.0.energy
-    }
-
-    fn get_energy_mut(&mut self) -> &mut crate::energy::Energy {
-        &mut self.0.energy
-    }
-
-    fn get_coordinate(&self) -> &crate::world::coordinates::Coordinate {
-        &self.0.coordinate
-    }
-
-    fn get_coordinate_mut(&mut self) -> &mut crate::world::coordinates::Coordinate {
-        &mut self.0.coordinate
-    }
-
-    fn get_backpack(&self) -> &crate::runner::backpack::BackPack {
-        &self.0.backpack
-    }
-
-    fn get_backpack_mut(&mut self) -> &mut crate::runner::backpack::BackPack {
-        &mut self.0.backpack
-    }
 }
 
 fn generate_map_of_type_and_content(tile_type: TileType, content: Content, size: usize) -> Vec<Vec<Tile>> {
@@ -125,6 +110,28 @@ fn generate_sunny_weather() -> EnvironmentalConditions {
     EnvironmentalConditions::new(&[Sunny], 15, 12).unwrap()
 }
 
+fn world_of(map: Vec<Vec<Tile>>) -> World {
+    World {
+        dimension: map.len(),
+        discoverable: 3,
+        environmental_conditions: generate_sunny_weather(),
+        score_counter: ScoreCounter::new(1.0, &map, None),
+        map,
+        robots: Default::default(),
+    }
+}
+
+fn robot_at(row: usize, col: usize, contents: Vec<(Content, usize)>) -> TestRobot {
+    TestRobot(Robot {
+        energy: Energy::new(MAX_ENERGY_LEVEL),
+        coordinate: Coordinate::new(row, col),
+        backpack: BackPack {
+            size: 20,
+            contents: contents.into_iter().collect(),
+        },
+    })
+}
+
 // fn gen_world(dimension: usize) -> World {
 //     let mut rng = rand::thread_rng();
 //     let mut map: Vec<Vec<Tile>> = Vec::new();
@@ -199,6 +206,7 @@ fn generate_sunny_weather() -> EnvironmentalConditions {
 #[test]
 #[ignore]
 pub(crate) fn testing() {
+    #[derive(RobotState)]
     struct MyRobot(Robot);
     struct WorldGenerator {
         size: usize,
@@ -348,26 +356,6 @@ pub(crate) fn testing() {
         fn handle_event(&mut self, event: Event) {
             println!("{:?}", event);
         }
-
-        fn get_energy_mut(&mut self) -> &mut Energy {
-            &mut self.0.energy
-        }
-        fn get_energy(&self) -> &Energy {
-            &self.0.energy
-        }
-
-        fn get_backpack(&self) -> &BackPack {
-            &self.0.backpack
-        }
-        fn get_backpack_mut(&mut self) -> &mut BackPack {
-            &mut self.0.backpack
-        }
-        fn get_coordinate(&self) -> &Coordinate {
-            &self.0.coordinate
-        }
-        fn get_coordinate_mut(&mut self) -> &mut Coordinate {
-            &mut self.0.coordinate
-        }
     }
 
     let r = MyRobot(Robot::new());
@@ -385,6 +373,7 @@ pub(crate) fn testing() {
 
 #[test]
 pub fn test_issue24() {
+    #[derive(RobotState)]
     struct MyRobot(Robot);
     struct WorldGenerator;
     impl Generator for WorldGenerator {
@@ -593,26 +582,6 @@ pub fn test_issue24() {
         fn handle_event(&mut self, event: Event) {
             println!("{:?}", event)
         }
-
-        fn get_energy_mut(&mut self) -> &mut Energy {
-            &mut self.0.energy
-        }
-        fn get_energy(&self) -> &Energy {
-            &self.0.energy
-        }
-
-        fn get_backpack(&self) -> &BackPack {
-            &self.0.backpack
-        }
-        fn get_backpack_mut(&mut self) -> &mut BackPack {
-            &mut self.0.backpack
-        }
-        fn get_coordinate(&self) -> &Coordinate {
-            &self.0.coordinate
-        }
-        fn get_coordinate_mut(&mut self) -> &mut Coordinate {
-            &mut self.0.coordinate
-        }
     }
 
     let r = MyRobot(Robot::new());
@@ -0,0 +1,128 @@
+use super::*;
+use crate::utils::pathfinding::{find_path, find_path_with_teleports, go_cost, Step};
+use crate::world::score::ScoreCounter;
+
+fn known(map: &[Vec<Tile>]) -> Vec<Vec<Option<Tile>>> {
+    map.iter().map(|row| row.iter().cloned().map(Some).collect()).collect()
+}
+
+#[test]
+fn find_path_avoids_walls_and_climbs() {
+    let mut map = generate_map_of_type_and_content(Grass, Content::None, 5);
+    // a wall between the start and the destination, with a hill in the shortest gap
+    for row in map.iter_mut().take(4) {
+        row[2].tile_type = Wall;
+    }
+    map[4][2].elevation = 3;
+    let conditions = generate_sunny_weather();
+
+    let (directions, energy) = find_path(&known(&map), (0, 0), (0, 4), &conditions).unwrap();
+    assert_eq!(
+        directions
+            .iter()
+            .filter(|direction| **direction == Direction::Down)
+            .count(),
+        4
+    );
+    assert_eq!(
+        directions
+            .iter()
+            .filter(|direction| **direction == Direction::Right)
+            .count(),
+        4
+    );
+    assert_eq!(
+        directions
+            .iter()
+            .filter(|direction| **direction == Direction::Up)
+            .count(),
+        4
+    );
+    let step = go_cost(&map[0][0], &map[0][1], &conditions);
+    assert_eq!(energy, 12 * step + 9);
+}
+
+#[test]
+fn find_path_only_uses_known_tiles() {
+    let map = generate_map_of_type_and_content(Grass, Content::None, 3);
+    let mut known_map = known(&map);
+    known_map[0][1] = None;
+    known_map[1][1] = None;
+    known_map[2][1] = None;
+    let conditions = generate_sunny_weather();
+
+    assert_eq!(find_path(&known_map, (0, 0), (0, 2), &conditions), None);
+    assert_eq!(find_path(&known_map, (0, 0), (0, 0), &conditions), Some((vec![], 0)));
+}
+
+#[test]
+fn find_path_energy_matches_go() {
+    let mut map = generate_map_of_type_and_content(Sand, Content::None, 4);
+    map[1][1].tile_type = Mountain;
+    map[1][2].tile_type = Hill;
+    map[2][1].elevation = 2;
+    map[3][3].tile_type = ShallowWater;
+    let mut world = World {
+        dimension: 4,
+        discoverable: 4,
+        environmental_conditions: generate_sunny_weather(),
+        score_counter: ScoreCounter::new(1.0, &map, None),
+        map,
+        robots: Default::default(),
+    };
+    let mut robot = TestRobot(Robot::new());
+
+    let (directions, energy) = find_path(&known(&world.map), (0, 0), (3, 3), &world.environmental_conditions).unwrap();
+    for direction in directions {
+        assert!(go(&mut robot, &mut world, direction).is_ok());
+    }
+    assert_eq!(
+        (robot.get_coordinate().get_row(), robot.get_coordinate().get_col()),
+        (3, 3)
+    );
+    assert_eq!(robot.get_energy().get_energy_level(), MAX_ENERGY_LEVEL - energy);
+}
+
+#[test]
+fn find_path_with_teleports_test() {
+    let mut map = generate_map_of_type_and_content(Mountain, Content::None, 6);
+    map[0][1].tile_type = Teleport(false);
+    map[5][5].tile_type = Teleport(true);
+    map[5][4].tile_type = Teleport(true);
+    let conditions = generate_sunny_weather();
+
+    let (steps, energy) = find_path_with_teleports(&known(&map), (0, 0), (5, 3), &conditions).unwrap();
+    assert_eq!(
+        steps,
+        vec![
+            Step::Go(Direction::Right),
+            Step::Teleport((5, 4)),
+            Step::Go(Direction::Left)
+        ]
+    );
+    assert_eq!(
+        energy,
+        go_cost(&map[0][0], &map[0][1], &conditions) + 30 + go_cost(&map[5][4], &map[5][3], &conditions)
+    );
+
+    // without teleports the robot has to climb the mountains
+    let (directions, _) = find_path(&known(&map), (0, 0), (5, 3), &conditions).unwrap();
+    assert_eq!(directions.len(), 8);
+}
+
+#[test]
+fn find_path_from_inactive_teleport_test() {
+    let mut map = generate_map_of_type_and_content(Mountain, Content::None, 4);
+    map[0][0].tile_type = Teleport(false);
+    map[3][3].tile_type = Teleport(true);
+    let conditions = generate_sunny_weather();
+
+    // the robot didn't walk onto the teleport it stands on, so it has to walk
+    let (steps, _) = find_path_with_teleports(&known(&map), (0, 0), (3, 2), &conditions).unwrap();
+    assert_eq!(steps.len(), 5);
+    assert!(steps.iter().all(|step| matches!(step, Step::Go(_))));
+
+    map[0][0].tile_type = Teleport(true);
+    let (steps, _) = find_path_with_teleports(&known(&map), (0, 0), (3, 2), &conditions).unwrap();
+    assert_eq!(steps, vec![Step::Teleport((3, 3)), Step::Go(Direction::Left)]);
+}
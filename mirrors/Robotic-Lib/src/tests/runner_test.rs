@@ -1,3 +1,11 @@
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+
+use super::*;
+use crate::runner::config::RunnerConfig;
+use crate::runner::observer::{Observer, TickSnapshot};
+use crate::runner::outcome::{EndCondition, EndReason};
+
 #[test]
 fn game_tick_test() {
     use super::*;
@@ -8,3 +16,299 @@ fn game_tick_test() {
     let run = Runner::new(Box::new(robot), &mut dummy_world);
     assert_eq!(run.unwrap().game_tick(), Ok(()));
 }
+
+// Destroys the rock on its right every tick
+#[derive(RobotState)]
+struct DestroyingRobot(Robot);
+
+impl Runnable for DestroyingRobot {
+    fn process_tick(&mut self, world: &mut World) {
+        let _ = destroy(self, world, Right);
+    }
+    fn handle_event(&mut self, _event: Event) {}
+}
+
+#[test]
+fn multi_robot_spawn_points_test() {
+    let robots: Vec<Box<dyn Runnable>> = vec![
+        Box::new(TestRobot(Robot::new())),
+        Box::new(TestRobot(Robot::new())),
+        Box::new(TestRobot(Robot::new())),
+    ];
+    let mut dummy_world = TestWorld::init(10);
+
+    let run = Runner::new_multi(robots, &mut dummy_world).unwrap();
+    let positions: Vec<(usize, usize)> = run
+        .get_robots()
+        .iter()
+        .map(|robot| (robot.get_coordinate().get_row(), robot.get_coordinate().get_col()))
+        .collect();
+    assert_eq!(positions, vec![(0, 0), (1, 0), (0, 1)]);
+}
+
+#[test]
+fn multi_robot_no_robots_test() {
+    let mut dummy_world = TestWorld::init(3);
+
+    assert_eq!(Runner::new_multi(vec![], &mut dummy_world).err(), Some(NoRobots));
+}
+
+#[test]
+fn multi_robot_not_enough_spawn_points_test() {
+    let robots: Vec<Box<dyn Runnable>> = vec![Box::new(TestRobot(Robot::new())), Box::new(TestRobot(Robot::new()))];
+    let mut dummy_world = TestWorld::init(1);
+
+    assert_eq!(
+        Runner::new_multi(robots, &mut dummy_world).err(),
+        Some(NotEnoughSpawnPoints)
+    );
+}
+
+#[test]
+fn multi_robot_score_test() {
+    let robots: Vec<Box<dyn Runnable>> = vec![
+        Box::new(DestroyingRobot(Robot::new())),
+        Box::new(TestRobot(Robot::new())),
+    ];
+    let mut dummy_world = TestWorld::init(10);
+
+    let mut run = Runner::new_multi(robots, &mut dummy_world).unwrap();
+    assert_eq!(run.game_tick(), Ok(()));
+
+    let scores = run.get_scores();
+    assert!(scores[0] > 0.);
+    assert_eq!(scores[1], 0.);
+    assert_eq!(
+        run.get_robots()[0].get_backpack().get_contents().get(&Rock(0)),
+        Some(&1)
+    );
+}
+
+#[test]
+fn multi_robot_map_test() {
+    use crate::interface::{robot_map, robot_view};
+
+    let mut world = World::new(
+        generate_map_of_type_and_content(Sand, Rock(1), 10),
+        generate_sunny_weather(),
+        1.0,
+        None,
+    );
+    world.add_robots(vec![(0, 0), (5, 5)]);
+    let robot = TestRobot(Robot::new());
+
+    world.start_turn(0, vec![(0, 0), (5, 5)]);
+    robot_view(&robot, &world);
+    let discovered = |world: &World| {
+        robot_map(world)
+            .unwrap()
+            .iter()
+            .flatten()
+            .filter(|tile| tile.is_some())
+            .count()
+    };
+    assert_eq!(discovered(&world), 4);
+
+    world.start_turn(1, vec![(0, 0), (5, 5)]);
+    assert_eq!(discovered(&world), 0);
+}
+
+#[test]
+fn world_is_send_test() {
+    fn assert_send<T: Send>() {}
+    assert_send::<World>();
+}
+
+#[cfg(feature = "send")]
+#[test]
+fn runner_on_another_thread_test() {
+    let mut dummy_world = TestWorld::init(10);
+    let mut run = Runner::new(Box::new(DestroyingRobot(Robot::new())), &mut dummy_world).unwrap();
+
+    let run = std::thread::spawn(move || {
+        assert_eq!(run.game_tick(), Ok(()));
+        run
+    })
+    .join()
+    .unwrap();
+    assert!(run.get_scores()[0] > 0.);
+}
+
+#[test]
+fn runner_config_test() {
+    let config = RunnerConfig {
+        recharge: |_| 7,
+        initial_energy: Some(100),
+        max_energy: 105,
+        backpack_size: 5,
+        discoverable: |dimension| dimension,
+        tick_limit: Some(2),
+        end_conditions: vec![],
+    };
+    let robots: Vec<Box<dyn Runnable>> = vec![Box::new(TestRobot(Robot::new()))];
+    let mut dummy_world = TestWorld::init(10);
+    let mut run = Runner::with_config(robots, &mut dummy_world, config).unwrap();
+
+    assert_eq!(run.get_robot().get_backpack().get_size(), 5);
+    assert_eq!(run.get_robot().get_energy().get_energy_level(), 100);
+    assert_eq!(run.world.discoverable, 10);
+
+    assert_eq!(run.game_tick(), Ok(()));
+    assert_eq!(run.get_robot().get_energy().get_energy_level(), 105);
+    assert_eq!(run.game_tick(), Ok(()));
+    assert_eq!(run.game_tick(), Err(GameOver));
+    assert_eq!(run.get_ticks(), 2);
+    assert_eq!(
+        run.get_outcome().map(|outcome| outcome.reason),
+        Some(EndReason::TickLimit)
+    );
+}
+
+#[test]
+fn runner_default_config_test() {
+    let mut dummy_world = TestWorld::init(10);
+    let run = Runner::new(Box::new(TestRobot(Robot::new())), &mut dummy_world).unwrap();
+
+    assert_eq!(run.get_robot().get_backpack().get_size(), 20);
+    assert_eq!(run.get_robot().get_energy().get_energy_level(), MAX_ENERGY_LEVEL);
+    assert_eq!(run.get_robot().get_energy().get_max_energy_level(), MAX_ENERGY_LEVEL);
+    assert_eq!(run.world.discoverable, 33);
+}
+
+// Remembers if it received `Terminated`
+#[derive(RobotState)]
+struct TerminatedRobot(#[robot] Robot, Arc<AtomicBool>);
+
+impl Runnable for TerminatedRobot {
+    fn process_tick(&mut self, _world: &mut World) {}
+    fn handle_event(&mut self, event: Event) {
+        if let Event::Terminated = event {
+            self.1.store(true, Ordering::SeqCst);
+        }
+    }
+}
+
+#[test]
+fn run_to_completion_score_reached_test() {
+    let config = RunnerConfig {
+        tick_limit: Some(100),
+        end_conditions: vec![EndCondition::ScoreReached(0.000001)],
+        ..Default::default()
+    };
+    let robots: Vec<Box<dyn Runnable>> = vec![Box::new(DestroyingRobot(Robot::new()))];
+    let mut dummy_world = TestWorld::init(10);
+    let mut run = Runner::with_config(robots, &mut dummy_world, config).unwrap();
+
+    let outcome = run.run_to_completion().unwrap();
+    assert_eq!(outcome.reason, EndReason::ScoreReached);
+    assert_eq!(outcome.ticks, 1);
+    assert!(outcome.score > 0.);
+    assert_eq!(outcome.scores, vec![outcome.score]);
+    assert!(outcome.energy_spent > 0);
+    assert_eq!(run.run_to_completion(), Err(GameOver));
+}
+
+#[test]
+fn run_to_completion_out_of_energy_test() {
+    let config = RunnerConfig {
+        recharge: |_| 0,
+        initial_energy: Some(0),
+        end_conditions: vec![EndCondition::OutOfEnergy(3), EndCondition::ScoreReached(1.0)],
+        ..Default::default()
+    };
+    let robots: Vec<Box<dyn Runnable>> = vec![
+        Box::new(DestroyingRobot(Robot::new())),
+        Box::new(TestRobot(Robot::new())),
+    ];
+    let mut dummy_world = TestWorld::init(10);
+    let mut run = Runner::with_config(robots, &mut dummy_world, config).unwrap();
+
+    let outcome = run.run_to_completion().unwrap();
+    assert_eq!(outcome.reason, EndReason::OutOfEnergy);
+    assert_eq!(outcome.ticks, 3);
+    assert_eq!(outcome.energy_spent, 0);
+}
+
+#[test]
+fn run_to_completion_without_end_condition_test() {
+    let mut dummy_world = TestWorld::init(10);
+    let mut run = Runner::new(Box::new(TestRobot(Robot::new())), &mut dummy_world).unwrap();
+
+    assert_eq!(run.run_to_completion(), Err(NoEndCondition));
+    assert_eq!(run.get_ticks(), 0);
+}
+
+#[test]
+fn terminate_test() {
+    let terminated = Arc::new(AtomicBool::new(false));
+    let robot = TerminatedRobot(Robot::new(), terminated.clone());
+    let mut dummy_world = TestWorld::init(10);
+    let mut run = Runner::new(Box::new(robot), &mut dummy_world).unwrap();
+
+    assert_eq!(run.game_tick(), Ok(()));
+    assert!(!terminated.load(Ordering::SeqCst));
+
+    let outcome = run.terminate();
+    assert!(terminated.load(Ordering::SeqCst));
+    assert_eq!(outcome.reason, EndReason::Stopped);
+    assert_eq!(outcome.ticks, 1);
+    assert_eq!(run.get_outcome(), Some(&outcome));
+    assert_eq!(run.game_tick(), Err(GameOver));
+}
+
+// Keeps what it observed
+#[derive(Default)]
+struct Observed {
+    events: Vec<(usize, Event)>,
+    snapshots: Vec<TickSnapshot>,
+}
+
+struct TestObserver(Arc<Mutex<Observed>>);
+
+impl Observer for TestObserver {
+    fn on_event(&mut self, robot: usize, event: &Event) {
+        self.0.lock().unwrap().events.push((robot, event.clone()));
+    }
+    fn on_tick(&mut self, snapshot: &TickSnapshot) {
+        self.0.lock().unwrap().snapshots.push(snapshot.clone());
+    }
+}
+
+#[test]
+fn observer_test() {
+    let robots: Vec<Box<dyn Runnable>> = vec![
+        Box::new(TestRobot(Robot::new())),
+        Box::new(DestroyingRobot(Robot::new())),
+    ];
+    let mut dummy_world = TestWorld::init(10);
+    let mut run = Runner::new_multi(robots, &mut dummy_world).unwrap();
+    let observed = Arc::new(Mutex::new(Observed::default()));
+    run.add_observer(Box::new(TestObserver(observed.clone())));
+
+    assert_eq!(run.game_tick(), Ok(()));
+
+    let observed = observed.lock().unwrap();
+    let events_of = |robot: usize| {
+        observed
+            .events
+            .iter()
+            .filter(|(index, _)| *index == robot)
+            .map(|(_, event)| event.clone())
+            .collect::<Vec<_>>()
+    };
+    assert!(matches!(
+        events_of(0)[..],
+        [Event::TimeChanged(_), Event::EnergyRecharged(10)]
+    ));
+    assert!(events_of(1).contains(&Event::AddedToBackpack(Rock(0), 1)));
+    assert!(matches!(events_of(1).last(), Some(Event::EnergyRecharged(10))));
+
+    assert_eq!(observed.snapshots.len(), 1);
+    let snapshot = &observed.snapshots[0];
+    assert_eq!(snapshot.tick, 1);
+    assert!(snapshot.score > 0.);
+    assert_eq!(snapshot.robots[1].position, (1, 0));
+    assert_eq!(snapshot.robots[1].backpack.get(&Rock(0)), Some(&1));
+    assert_eq!(snapshot.robots[0].score, 0.);
+    assert_eq!(snapshot.robots[1].score, snapshot.score);
+}
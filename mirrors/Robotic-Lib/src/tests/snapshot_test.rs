@@ -0,0 +1,120 @@
+use std::env;
+
+use super::*;
+use crate::runner::snapshot::{SaveState, SnapshotError};
+
+// Counts its ticks and saves the count in the snapshots
+#[derive(RobotState)]
+struct CountingRobot {
+    #[robot]
+    robot: Robot,
+    ticks: u8,
+}
+
+impl SaveState for CountingRobot {
+    fn save_state(&self) -> Vec<u8> {
+        vec![self.ticks]
+    }
+    fn restore_state(&mut self, state: &[u8]) -> Result<(), SnapshotError> {
+        self.ticks = *state
+            .first()
+            .ok_or(SnapshotError::RobotState("empty state".to_string()))?;
+        Ok(())
+    }
+}
+
+impl Runnable for CountingRobot {
+    fn process_tick(&mut self, world: &mut World) {
+        self.ticks += 1;
+        let _ = destroy(self, world, Right);
+        let _ = go(self, world, Down);
+    }
+    fn handle_event(&mut self, _event: Event) {}
+    fn get_save_state(&self) -> Option<&dyn SaveState> {
+        Some(self)
+    }
+    fn get_save_state_mut(&mut self) -> Option<&mut dyn SaveState> {
+        Some(self)
+    }
+}
+
+fn snapshot_path(name: &str) -> String {
+    env::temp_dir()
+        .join(format!("robotics_lib_{}_{}.snapshot", name, std::process::id()))
+        .to_string_lossy()
+        .to_string()
+}
+
+fn same_state(saved: &Runner, restored: &Runner) {
+    let (saved_robot, restored_robot) = (saved.get_robot(), restored.get_robot());
+    assert_eq!(saved_robot.get_energy(), restored_robot.get_energy());
+    assert_eq!(saved_robot.get_coordinate(), restored_robot.get_coordinate());
+    assert_eq!(
+        saved_robot.get_backpack().get_size(),
+        restored_robot.get_backpack().get_size()
+    );
+    assert_eq!(
+        saved_robot.get_backpack().get_contents(),
+        restored_robot.get_backpack().get_contents()
+    );
+    assert_eq!(
+        saved_robot.get_save_state().map(|state| state.save_state()),
+        restored_robot.get_save_state().map(|state| state.save_state())
+    );
+    assert_eq!(saved.get_scores(), restored.get_scores());
+}
+
+#[test]
+fn snapshot_restores_the_game() {
+    let path = snapshot_path("restore");
+    let mut dummy_world = TestWorld::init(10);
+    let robot = CountingRobot {
+        robot: Robot::new(),
+        ticks: 0,
+    };
+
+    let mut run = Runner::new(Box::new(robot), &mut dummy_world).unwrap();
+    for _ in 0..3 {
+        run.game_tick().unwrap();
+    }
+    run.save(&path).unwrap();
+
+    let robot = CountingRobot {
+        robot: Robot::new(),
+        ticks: 0,
+    };
+    let mut restored = Runner::restore(Box::new(robot), &path).unwrap();
+    std::fs::remove_file(&path).unwrap();
+    same_state(&run, &restored);
+    assert_eq!(
+        restored.get_robot().get_save_state().map(|state| state.save_state()),
+        Some(vec![3])
+    );
+
+    // the map was restored too, so both games go on the same way
+    for _ in 0..3 {
+        run.game_tick().unwrap();
+        restored.game_tick().unwrap();
+        same_state(&run, &restored);
+    }
+}
+
+#[test]
+fn snapshot_with_wrong_robot_count() {
+    let path = snapshot_path("count");
+    let mut dummy_world = TestWorld::init(10);
+
+    let run = Runner::new(Box::new(TestRobot(Robot::new())), &mut dummy_world).unwrap();
+    run.save(&path).unwrap();
+
+    let robots: Vec<Box<dyn Runnable>> = vec![Box::new(TestRobot(Robot::new())), Box::new(TestRobot(Robot::new()))];
+    let result = Runner::restore_multi(robots, &path);
+    std::fs::remove_file(&path).unwrap();
+    assert!(matches!(
+        result,
+        Err(SnapshotError::WrongRobotCount {
+            expected: 1,
+            found: 2
+        })
+    ));
+}
@@ -0,0 +1,106 @@
+use crate::testing::ascii_world::{AsciiMapError, AsciiWorld};
+use crate::testing::scripted_robot::ScriptedRobot;
+
+use super::*;
+
+#[test]
+fn ascii_world_parse_test() {
+    let mut world = AsciiWorld::parse(
+        "
+        G    Gr3  H^2
+        W    R@   Mt5^10
+        Xk10 Gf   T
+        ",
+    )
+    .unwrap()
+    .with_weather(&[Rainy]);
+
+    let (map, spawn, environmental_conditions, max_score, score_table) = world.gen();
+    assert_eq!(spawn, (1, 1));
+    assert_eq!(environmental_conditions.get_weather_condition(), Rainy);
+    assert_eq!(max_score, 1.0);
+    assert_eq!(score_table, None);
+
+    let tile = |tile_type, content, elevation| Tile {
+        tile_type,
+        content,
+        elevation,
+    };
+    assert_eq!(
+        map,
+        vec![
+            vec![
+                tile(Grass, Content::None, 0),
+                tile(Grass, Rock(3), 0),
+                tile(Hill, Content::None, 2)
+            ],
+            vec![
+                tile(ShallowWater, Content::None, 0),
+                tile(Street, Content::None, 0),
+                tile(Mountain, Tree(5), 10)
+            ],
+            vec![
+                tile(Wall, Bank(0..10), 0),
+                tile(Grass, Fire, 0),
+                tile(Teleport(false), Content::None, 0)
+            ],
+        ]
+    );
+}
+
+#[test]
+fn ascii_world_errors_test() {
+    assert_eq!(AsciiWorld::parse("\n  \n").err(), Some(AsciiMapError::Empty));
+    assert_eq!(
+        AsciiWorld::parse("G G\nG").err(),
+        Some(AsciiMapError::NotSquare { row: 1 })
+    );
+    assert_eq!(
+        AsciiWorld::parse("G Gq\nG G").err(),
+        Some(AsciiMapError::InvalidCell {
+            row: 0,
+            col: 1,
+            cell: "Gq".to_string()
+        })
+    );
+    assert_eq!(
+        AsciiWorld::parse("G Gr^\nG G").err(),
+        Some(AsciiMapError::InvalidCell {
+            row: 0,
+            col: 1,
+            cell: "Gr^".to_string()
+        })
+    );
+    assert_eq!(
+        AsciiWorld::parse("G@ G\nG G@").err(),
+        Some(AsciiMapError::MultipleSpawns)
+    );
+}
+
+#[test]
+fn scripted_robot_test() {
+    let mut world = AsciiWorld::parse(
+        "
+        S@ Sr2 S
+        S  S   S
+        S  S   S
+        ",
+    )
+    .unwrap();
+    let (map, ..) = world.gen();
+    let mut world = World::new(map, generate_sunny_weather(), 1.0, None);
+
+    let mut robot = ScriptedRobot::new(|robot, world| {
+        let _ = destroy(robot, world, Right);
+        let _ = go(robot, world, Right);
+    });
+    // the Runner isn't there to give it a backpack
+    robot.get_backpack_mut().size = 20;
+    robot.process_tick(&mut world);
+
+    assert_eq!(robot.get_coordinate(), &Coordinate::new(0, 1));
+    assert_eq!(robot.get_backpack().get_contents().get(&Rock(0)), Some(&2));
+    assert!(robot
+        .get_events()
+        .contains(&Event::Moved(world.map[0][1].clone(), (0, 1))));
+}
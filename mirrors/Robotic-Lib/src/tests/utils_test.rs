@@ -8,6 +8,7 @@ fn can_not_go_up_and_left_from_tile_zero_zero() {
         discoverable: 4 / 10 + 1,
         environmental_conditions: generate_sunny_weather(),
         score_counter: Default::default(),
+        robots: Default::default(),
     };
 
     let robot = TestRobot(Robot::new());
@@ -23,6 +24,7 @@ fn can_not_move_anywhere_if_world_size_is_one() {
         discoverable: 1 / 10 + 1,
         environmental_conditions: generate_sunny_weather(),
         score_counter: Default::default(),
+        robots: Default::default(),
     };
 
     // Assuming the Robot::new method will set (0, 0) as coordinates.
@@ -44,6 +46,7 @@ fn can_move_down_and_right_from_tile_zero_zero() {
         discoverable: 4 / 10 + 1,
         environmental_conditions: generate_sunny_weather(),
         score_counter: Default::default(),
+        robots: Default::default(),
     };
 
     // Assuming the Robot::new method will set (0, 0) as coordinates.
@@ -61,6 +64,7 @@ fn can_not_move_on_deep_water_tile() {
         discoverable: 4 / 10 + 1,
         environmental_conditions: generate_sunny_weather(),
         score_counter: Default::default(),
+        robots: Default::default(),
     };
     let robot = TestRobot(Robot::new());
     assert_eq!(go_allowed(&robot, &world, &Direction::Down), Err(LibError::CannotWalk));
@@ -88,6 +92,7 @@ fn a_tree_will_be_destroyed() {
         discoverable: 1 / 10 + 1,
         environmental_conditions: generate_sunny_weather(),
         score_counter: Default::default(),
+        robots: Default::default(),
     };
     assert_eq!(can_destroy(&world, (0, 0)), Ok(true));
 }
@@ -100,6 +105,7 @@ fn a_bin_will_not_be_destroyed() {
         discoverable: 4 / 10 + 1,
         environmental_conditions: generate_sunny_weather(),
         score_counter: Default::default(),
+        robots: Default::default(),
     };
     assert_eq!(can_destroy(&world, (0, 0)), Ok(false));
 }
@@ -112,6 +118,7 @@ fn a_bank_will_not_be_destroyed() {
         discoverable: 1 / 10 + 1,
         environmental_conditions: generate_sunny_weather(),
         score_counter: Default::default(),
+        robots: Default::default(),
     };
     assert_eq!(can_destroy(&world, (0, 0)), Ok(false));
 }
@@ -124,6 +131,7 @@ fn a_crate_will_not_be_destroyed() {
         discoverable: 1 / 10 + 1,
         environmental_conditions: generate_sunny_weather(),
         score_counter: Default::default(),
+        robots: Default::default(),
     };
     assert_eq!(can_destroy(&world, (0, 0)), Ok(false));
 }
@@ -136,6 +144,7 @@ fn a_coin_will_be_destroyed() {
         discoverable: 1 / 10 + 1,
         environmental_conditions: generate_sunny_weather(),
         score_counter: Default::default(),
+        robots: Default::default(),
     };
     assert_eq!(can_destroy(&world, (0, 0)), Ok(true));
 }
@@ -148,6 +157,7 @@ fn fire_will_be_destroyed() {
         discoverable: 1 / 10 + 1,
         environmental_conditions: generate_sunny_weather(),
         score_counter: Default::default(),
+        robots: Default::default(),
     };
     assert_eq!(can_destroy(&world, (0, 0)), Ok(true));
 }
@@ -160,6 +170,7 @@ fn garbage_will_be_destroyed() {
         discoverable: 1 / 10 + 1,
         environmental_conditions: generate_sunny_weather(),
         score_counter: Default::default(),
+        robots: Default::default(),
     };
     assert_eq!(can_destroy(&world, (0, 0)), Ok(true));
 }
@@ -172,6 +183,7 @@ fn none_will_not_be_destroyed() {
         discoverable: 1 / 10 + 1,
         environmental_conditions: generate_sunny_weather(),
         score_counter: Default::default(),
+        robots: Default::default(),
     };
     assert_eq!(can_destroy(&world, (0, 0)), Err(NoContent));
 }
@@ -184,6 +196,7 @@ fn water_will_be_destroyed() {
         discoverable: 1 / 10 + 1,
         environmental_conditions: generate_sunny_weather(),
         score_counter: Default::default(),
+        robots: Default::default(),
     };
     assert_eq!(can_destroy(&world, (0, 0)), Ok(true));
 }
@@ -226,3 +239,20 @@ fn add_tree_to_backpack_with_not_enough_space() {
         Err(LibError::NotEnoughSpace(6))
     );
 }
+
+#[test]
+fn can_not_go_on_a_tile_occupied_by_another_robot() {
+    let mut world = World {
+        map: generate_map_of_type_and_content(Grass, Content::None, 4),
+        dimension: 4,
+        discoverable: 4 / 10 + 1,
+        environmental_conditions: generate_sunny_weather(),
+        score_counter: Default::default(),
+        robots: Default::default(),
+    };
+    world.add_robots(vec![(0, 0), (0, 1)]);
+
+    let robot = TestRobot(Robot::new());
+    assert_eq!(go_allowed(&robot, &world, &Direction::Right), Err(TileOccupied));
+    assert_eq!(go_allowed(&robot, &world, &Direction::Down), Ok(()));
+}
@@ -143,6 +143,7 @@ mod world_struct_tests {
             discoverable: 1 / 10 + 1,
             environmental_conditions,
             score_counter: Default::default(),
+            robots: Default::default(),
         };
         // let mut world = World::new(map.clone(), environmental_conditions, 1.);
 
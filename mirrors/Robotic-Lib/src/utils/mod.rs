@@ -1,11 +1,12 @@
 use std::cmp::min;
-use std::sync::Mutex;
 
-use crate::event::events::Event::{AddedToBackpack, RemovedFromBackpack};
+use crate::event::events::Event::{AddedToBackpack, RemovedFromBackpack, ScoreIncreased};
+use crate::event::events::ScoreReason;
+use crate::event::fire;
 use crate::interface::Direction;
 use crate::runner::Runnable;
 use crate::utils::LibError::{
-    CannotWalk, NoContent, NotEnoughEnergy, NotEnoughSpace, OperationNotAllowed, OutOfBounds,
+    CannotWalk, NoContent, NotEnoughEnergy, NotEnoughSpace, OperationNotAllowed, OutOfBounds, TileOccupied,
 };
 use crate::world::coordinates::Coordinate;
 use crate::world::environmental_conditions::{DayTime, EnvironmentalConditions, WeatherType};
@@ -14,6 +15,8 @@ use crate::world::tile::TileType;
 use crate::world::tile::TileType::Teleport;
 use crate::world::World;
 
+pub mod pathfinding;
+
 /// It contains all the errors that can be returned by the library
 ///
 /// # Variants
@@ -35,6 +38,11 @@ use crate::world::World;
 /// - `ContentValueIsHigherThanMax`,
 /// - `WronContentNotAllowedOnTilegHour`,
 /// - `MustDestroyContentFirst`: To complete an operation on a tile the destruction of its content is needed,
+/// - `TileOccupied`: Another robot is on the desired tile
+/// - `NotEnoughSpawnPoints`: The generator didn't give a valid spawn point for every robot
+/// - `NoRobots`: A Runner was created without any robot
+/// - `GameOver`: The game has ended, see `Runner::get_outcome`
+/// - `NoEndCondition`: The game has no tick limit and no end condition, so it would never end
 ///
 /// # Examples
 ///
@@ -76,7 +84,12 @@ pub enum LibError {
     TeleportIsTrueOnGeneration,
     ContentValueIsHigherThanMax,
     ContentNotAllowedOnTile,
-    MustDestroyContentFirst, //other
+    MustDestroyContentFirst,
+    TileOccupied,
+    NotEnoughSpawnPoints,
+    NoRobots,
+    GameOver,
+    NoEndCondition, //other
 }
 
 /// This function is used to check if the robot can go in the direction passed as argument
@@ -93,6 +106,7 @@ pub enum LibError {
 /// - `NoTileTypeProps`: The TileTypeProp of the target cell is not set properly
 /// - `OutOfBounds`: The robot couldn't be moved cause it's on the border an the chosen direction is out of bounds
 /// - `CannotWalk`: The robot cannot walk on the desired tiletype
+/// - `TileOccupied`: Another robot is on the desired tile
 ///
 /// # Examples
 ///
@@ -124,6 +138,11 @@ pub fn go_allowed(robot: &impl Runnable, world: &World, direction: &Direction) -
         return Err(CannotWalk);
     }
 
+    let (row, col) = get_coords_row_col(robot, direction);
+    if world.is_occupied(row, col) {
+        return Err(TileOccupied);
+    }
+
     Ok(())
 }
 
@@ -207,6 +226,7 @@ pub(crate) fn get_coords_row_col(robot: &impl Runnable, direction: &Direction) -
 /// # Errors:
 /// - `OutOfBounds`: The robot couldn't be teleported because the coordinate given is out of bound
 /// - `OperationNotAllowed`: The robot isn't in a teleport tile or it's trying to teleport itself in a tile which isn't a teleport tile too
+/// - `TileOccupied`: Another robot is on the destination teleport
 ///
 pub fn teleport_allowed(robot: &impl Runnable, world: &World, coordinates: &Coordinate) -> Result<(), LibError> {
     let (teleport_row, teleport_col) = (coordinates.get_row(), coordinates.get_col());
@@ -220,6 +240,9 @@ pub fn teleport_allowed(robot: &impl Runnable, world: &World, coordinates: &Coor
     {
         return Err(OperationNotAllowed);
     }
+    if world.is_occupied(teleport_row, teleport_col) {
+        return Err(TileOccupied);
+    }
     Ok(())
 }
 
@@ -268,7 +291,7 @@ pub(crate) fn add_to_backpack(robot: &mut impl Runnable, content: Content, quant
         .entry(content.to_default())
         .or_insert(0) += quantity_to_add;
 
-    robot.handle_event(AddedToBackpack(content, quantity_to_add));
+    fire(robot, AddedToBackpack(content, quantity_to_add));
 
     if remainder >= quantity {
         Ok(quantity_to_add)
@@ -323,12 +346,24 @@ pub(crate) fn remove_from_backpack(
     };
 
     if remove_result.is_ok() {
-        robot.handle_event(RemovedFromBackpack(content.clone(), result))
+        fire(robot, RemovedFromBackpack(content.clone(), result))
     }
 
     remove_result
 }
 
+/// Fires `ScoreIncreased` if the robot made the score increase
+///
+/// # Arguments
+/// - robot: The robot that made the score increase
+/// - delta: The score added
+/// - reason: Why the score was added
+pub(crate) fn score_increased(robot: &mut impl Runnable, delta: f32, reason: ScoreReason) {
+    if delta > 0. {
+        fire(robot, ScoreIncreased { delta, reason });
+    }
+}
+
 /// A function used for the put interface combined with the store `Content`
 ///
 /// # Arguments
@@ -362,15 +397,6 @@ pub(crate) fn can_store(
     Ok((quantity_to_remove, cost * quantity_to_remove))
 }
 
-/// A utility function used to keep track of the visited tiles (PLOT)
-pub(crate) fn add_to_plot(plot: &Mutex<Vec<(usize, usize)>>, row: usize, col: usize) {
-    if let Ok(mut plot_guard) = plot.lock() {
-        if !plot_guard.contains(&(row, col)) {
-            plot_guard.push((row, col));
-        }
-    }
-}
-
 /// A function which is used to calculate the energy requirements for the monodirectional view
 ///
 /// # Arguments
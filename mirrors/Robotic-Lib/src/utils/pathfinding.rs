@@ -0,0 +1,197 @@
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap};
+
+use crate::interface::Direction;
+use crate::interface::TELEPORT_COST;
+use crate::utils::calculate_cost_go_with_environment;
+use crate::world::environmental_conditions::EnvironmentalConditions;
+use crate::world::tile::Tile;
+use crate::world::tile::TileType::Teleport;
+
+/// A step of a path found by `find_path_with_teleports`
+///
+/// # Variants
+/// - `Go(Direction)`: Move to the adjacent tile with the `go` interface
+/// - `Teleport((usize, usize))`: Move to the teleport at the given (row, col) with the `teleport` interface
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Step {
+    Go(Direction),
+    Teleport((usize, usize)),
+}
+
+/// Returns the energy needed by the `go` interface to move from a tile to an adjacent one
+///
+/// # Arguments
+/// - `from`: The tile the robot is on
+/// - `to`: The tile the robot moves to
+/// - `environmental_conditions`: The weather and the time of day, see `look_at_sky`
+///
+/// # Returns
+/// The cost of the tile type, increased by the environmental conditions, plus the square of the elevation climbed
+pub fn go_cost(from: &Tile, to: &Tile, environmental_conditions: &EnvironmentalConditions) -> usize {
+    let cost = calculate_cost_go_with_environment(
+        to.tile_type.properties().cost(),
+        environmental_conditions.clone(),
+        to.tile_type,
+    );
+    // climbing costs, going down is free
+    let elevation_cost = to.elevation.saturating_sub(from.elevation).pow(2);
+    cost + elevation_cost
+}
+
+/// Finds the path that needs the least energy between two tiles of a map, walking with the `go` interface
+///
+/// Only the known tiles are used, so the map is usually the one returned by `robot_map`. The costs are the ones of
+/// `go` under the given environmental conditions, they change with the weather and the time of day.
+///
+/// # Arguments
+/// - `map`: The map known by the robot
+/// - `from`: The (row, col) of the start
+/// - `to`: The (row, col) of the destination
+/// - `environmental_conditions`: The conditions used to compute the costs, see `look_at_sky`
+///
+/// # Returns
+/// - `Some`: The directions to give to `go` in order, and the energy they need
+/// - `None`: The destination can't be reached through known walkable tiles
+///
+/// # Examples
+/// ```rust
+/// use robotics_lib::interface::{go, look_at_sky, robot_map};
+/// use robotics_lib::runner::Runnable;
+/// use robotics_lib::utils::pathfinding::find_path;
+/// use robotics_lib::world::World;
+///
+/// fn go_to(robot: &mut impl Runnable, world: &mut World, destination: (usize, usize)) {
+///     let map = robot_map(world).unwrap();
+///     let from = (robot.get_coordinate().get_row(), robot.get_coordinate().get_col());
+///     if let Some((directions, energy)) = find_path(&map, from, destination, &look_at_sky(world)) {
+///         if robot.get_energy().has_enough_energy(energy) {
+///             for direction in directions {
+///                 let _ = go(robot, world, direction);
+///             }
+///         }
+///     }
+/// }
+/// ```
+pub fn find_path(
+    map: &[Vec<Option<Tile>>],
+    from: (usize, usize),
+    to: (usize, usize),
+    environmental_conditions: &EnvironmentalConditions,
+) -> Option<(Vec<Direction>, usize)> {
+    let (steps, energy) = shortest_path(map, from, to, environmental_conditions, false)?;
+    let directions = steps
+        .into_iter()
+        .filter_map(|step| match step {
+            | Step::Go(direction) => Some(direction),
+            | Step::Teleport(_) => None,
+        })
+        .collect();
+    Some((directions, energy))
+}
+
+/// Like `find_path`, but the robot can also use the known active teleports
+///
+/// A teleport can be used from every teleport tile of the path (walking on a teleport activates it) to every
+/// known teleport that is already active.
+///
+/// # Returns
+/// - `Some`: The steps to take in order, and the energy they need
+/// - `None`: The destination can't be reached through known walkable tiles and teleports
+pub fn find_path_with_teleports(
+    map: &[Vec<Option<Tile>>],
+    from: (usize, usize),
+    to: (usize, usize),
+    environmental_conditions: &EnvironmentalConditions,
+) -> Option<(Vec<Step>, usize)> {
+    shortest_path(map, from, to, environmental_conditions, true)
+}
+
+// Dijkstra over the known tiles, the costs can be zero so there is no admissible heuristic for an A*
+fn shortest_path(
+    map: &[Vec<Option<Tile>>],
+    from: (usize, usize),
+    to: (usize, usize),
+    environmental_conditions: &EnvironmentalConditions,
+    use_teleports: bool,
+) -> Option<(Vec<Step>, usize)> {
+    let known = |(row, col): (usize, usize)| map.get(row).and_then(|tiles| tiles.get(col)).and_then(Option::as_ref);
+
+    known(from)?;
+    if !known(to)?.tile_type.properties().walk() {
+        return None;
+    }
+
+    let teleports: Vec<(usize, usize)> = if use_teleports {
+        map.iter()
+            .enumerate()
+            .flat_map(|(row, tiles)| {
+                tiles.iter().enumerate().filter_map(move |(col, tile)| match tile {
+                    | Some(tile) if tile.tile_type == Teleport(true) => Some((row, col)),
+                    | _ => None,
+                })
+            })
+            .collect()
+    } else {
+        vec![]
+    };
+
+    let mut energy = HashMap::from([(from, 0)]);
+    let mut previous: HashMap<(usize, usize), ((usize, usize), Step)> = HashMap::new();
+    let mut queue = BinaryHeap::from([Reverse((0, from))]);
+
+    while let Some(Reverse((cost, position))) = queue.pop() {
+        if position == to {
+            let mut steps = vec![];
+            let mut current = to;
+            while let Some((before, step)) = previous.remove(&current) {
+                steps.push(step);
+                current = before;
+            }
+            steps.reverse();
+            return Some((steps, cost));
+        }
+        if cost > energy[&position] {
+            continue;
+        }
+
+        let tile = known(position)?;
+        let mut edges = vec![];
+        for direction in [Direction::Up, Direction::Down, Direction::Left, Direction::Right] {
+            let next = match direction {
+                | Direction::Up if position.0 > 0 => (position.0 - 1, position.1),
+                | Direction::Down => (position.0 + 1, position.1),
+                | Direction::Left if position.1 > 0 => (position.0, position.1 - 1),
+                | Direction::Right => (position.0, position.1 + 1),
+                | _ => continue,
+            };
+            if let Some(next_tile) = known(next) {
+                if next_tile.tile_type.properties().walk() {
+                    edges.push((
+                        next,
+                        go_cost(tile, next_tile, environmental_conditions),
+                        Step::Go(direction),
+                    ));
+                }
+            }
+        }
+        // walking onto a teleport activates it, but the robot can start on one it never walked onto
+        let active = tile.tile_type == Teleport(true) || (matches!(tile.tile_type, Teleport(_)) && position != from);
+        if active {
+            for teleport in teleports.iter().filter(|teleport| **teleport != position) {
+                edges.push((*teleport, TELEPORT_COST, Step::Teleport(*teleport)));
+            }
+        }
+
+        for (next, step_cost, step) in edges {
+            let next_cost = cost + step_cost;
+            if energy.get(&next).is_none_or(|known_cost| next_cost < *known_cost) {
+                energy.insert(next, next_cost);
+                previous.insert(next, (position, step));
+                queue.push(Reverse((next_cost, next)));
+            }
+        }
+    }
+
+    None
+}
@@ -1,3 +1,5 @@
+use serde::{Deserialize, Serialize};
+
 /// Coordinate struct
 /// The `Coordinate` struct is used to define the coordinates of a tile.
 ///
@@ -13,7 +15,7 @@
 /// let robot = Robot::new();
 /// let row = robot.coordinate.get_row();
 /// ```
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Debug, PartialEq, Eq, Serialize, Deserialize)]
 pub struct Coordinate {
     row: usize,
     col: usize,
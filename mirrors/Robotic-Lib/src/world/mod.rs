@@ -1,5 +1,7 @@
-use std::collections::HashMap;
+use std::cell::RefCell;
+use std::collections::{HashMap, HashSet};
 
+use crate::runner::config::default_discoverable;
 use crate::world::environmental_conditions::EnvironmentalConditions;
 use crate::world::score::ScoreCounter;
 use crate::world::tile::{Content, Tile};
@@ -35,6 +37,7 @@ pub mod score;
 /// - `discoverable`: The number of discoverable tiles left (default: 30% of the world's dimension)
 /// - `environmental_conditions`: The environmental conditions of the world (daytime and weather).
 /// - `score_counter`: ScoreCounter struct keeping track of Robot's score.
+/// - `robots`: RobotStates struct keeping track of the robots sharing the world.
 #[derive(Debug)]
 pub struct World {
     pub(crate) map: Vec<Vec<Tile>>,
@@ -42,6 +45,29 @@ pub struct World {
     pub(crate) discoverable: usize,
     pub(crate) environmental_conditions: EnvironmentalConditions,
     pub(crate) score_counter: ScoreCounter,
+    pub(crate) robots: RobotStates,
+}
+
+/// Keeps track of the robots sharing the world.
+///
+/// The runner gives the turn to one robot at a time, the interfaces use this struct to know which robot is
+/// acting and where the other ones are.
+///
+/// # Fields
+/// - `active`: The index of the robot whose turn it is.
+/// - `positions`: The positions of every robot, taken at the start of the active robot's turn.
+/// - `scores`: The score of every robot, not counting the current turn.
+/// - `turn_start_score`: The world score at the start of the active robot's turn.
+/// - `discovered`: The tiles discovered by every robot.
+///
+/// A world without robots (like the ones built by hand in the tests) behaves like a single robot world.
+#[derive(Debug, Default)]
+pub(crate) struct RobotStates {
+    pub(crate) active: usize,
+    pub(crate) positions: Vec<(usize, usize)>,
+    pub(crate) scores: Vec<f32>,
+    pub(crate) turn_start_score: f32,
+    pub(crate) discovered: RefCell<Vec<HashSet<(usize, usize)>>>,
 }
 
 impl World {
@@ -56,9 +82,10 @@ impl World {
         World {
             map,
             dimension,
-            discoverable: (dimension.pow(2) / 10 + 1) * 3,
+            discoverable: default_discoverable(dimension),
             environmental_conditions,
             score_counter,
+            robots: RobotStates::default(),
         }
     }
 
@@ -73,4 +100,86 @@ impl World {
     pub fn get_discoverable(&mut self) -> usize {
         self.discoverable
     }
+
+    /// Places the robots in the world, each one starts with no score and no discovered tiles
+    pub(crate) fn add_robots(&mut self, positions: Vec<(usize, usize)>) {
+        self.robots = RobotStates {
+            active: 0,
+            scores: vec![0.; positions.len()],
+            turn_start_score: self.score_counter.get_score(),
+            discovered: RefCell::new(vec![HashSet::new(); positions.len()]),
+            positions,
+        };
+    }
+
+    /// Gives the turn to the robot at index `robot`
+    ///
+    /// # Arguments
+    /// - `robot`: The index of the robot
+    /// - `positions`: The current positions of all the robots
+    pub(crate) fn start_turn(&mut self, robot: usize, positions: Vec<(usize, usize)>) {
+        self.robots.active = robot;
+        self.robots.positions = positions;
+        self.robots.turn_start_score = self.score_counter.get_score();
+    }
+
+    /// Assigns the score made during the turn to the active robot
+    pub(crate) fn end_turn(&mut self) {
+        let turn_score = self.score_counter.get_score() - self.robots.turn_start_score;
+        if let Some(score) = self.robots.scores.get_mut(self.robots.active) {
+            *score += turn_score;
+        }
+        self.robots.turn_start_score = self.score_counter.get_score();
+    }
+
+    /// # Returns
+    /// `true` if a robot other than the active one is on the tile
+    pub(crate) fn is_occupied(&self, row: usize, col: usize) -> bool {
+        self.robots
+            .positions
+            .iter()
+            .enumerate()
+            .any(|(robot, position)| robot != self.robots.active && *position == (row, col))
+    }
+
+    /// Marks the tile as discovered by the active robot
+    pub(crate) fn discover(&self, row: usize, col: usize) {
+        let mut discovered = self.robots.discovered.borrow_mut();
+        // a world without robots still keeps track of what the robot using it discovered
+        if discovered.len() <= self.robots.active {
+            discovered.resize(self.robots.active + 1, HashSet::new());
+        }
+        discovered[self.robots.active].insert((row, col));
+    }
+
+    /// # Returns
+    /// The map with only the tiles discovered by the given robot
+    pub(crate) fn discovered_map(&self, robot: usize) -> Vec<Vec<Option<Tile>>> {
+        let mut map = vec![vec![None; self.dimension]; self.dimension];
+        if let Some(discovered) = self.robots.discovered.borrow().get(robot) {
+            for (row, col) in discovered.iter() {
+                map[*row][*col] = Some(self.map[*row][*col].clone());
+            }
+        }
+        map
+    }
+
+    /// # Returns
+    /// The score of the active robot, the whole world score if there are no robots
+    pub(crate) fn robot_score(&self) -> f32 {
+        match self.robots.scores.get(self.robots.active) {
+            | Some(score) => score + self.score_counter.get_score() - self.robots.turn_start_score,
+            | None => self.score_counter.get_score(),
+        }
+    }
+
+    /// # Returns
+    /// The score of every robot
+    pub(crate) fn robot_scores(&self) -> Vec<f32> {
+        let mut scores = self.robots.scores.clone();
+        if let Some(score) = scores.get_mut(self.robots.active) {
+            *score += self.score_counter.get_score() - self.robots.turn_start_score;
+        }
+        scores
+    }
 }
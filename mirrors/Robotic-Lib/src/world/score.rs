@@ -1,7 +1,6 @@
 use std::cell::RefCell;
 use std::collections::HashMap;
 
-use std::rc::Rc;
 use strum::IntoEnumIterator;
 
 use crate::world::tile::Content::{
@@ -29,16 +28,16 @@ use crate::world::tile::{Content, Tile};
 ///
 /// # Fields
 ///
-/// - `score`: An Rc<RefCell<f32>> container, holding the current value of score.
+/// - `score`: A RefCell<f32> container, holding the current value of score.
 /// - `max_score`: An f32 value of the max score, given during the initialization of the `World`.
 /// - `score_table`: A HashMap used inside of the `ScoreCounter` to increment score field.
 ///
 /// # Remarks
 ///
-/// `ScoreCounter` does not operate multi-thread. In case of such usage panics might occur.
+/// `ScoreCounter` can be moved to another thread together with its `World`, but it can't be shared between threads.
 #[derive(Debug, Clone, PartialEq)]
 pub(crate) struct ScoreCounter {
-    pub(crate) score: Rc<RefCell<f32>>,
+    pub(crate) score: RefCell<f32>,
     pub(crate) max_score: f32,
     pub(crate) score_table: HashMap<Content, f32>,
 }
@@ -63,7 +62,7 @@ impl ScoreCounter {
         score_table: Option<HashMap<Content, f32>>,
     ) -> ScoreCounter {
         ScoreCounter {
-            score: Rc::new(RefCell::new(0.)),
+            score: RefCell::new(0.),
             max_score,
             score_table: ScoreCounter::init_score_table(map, max_score, score_table),
         }
@@ -379,8 +378,14 @@ impl ScoreCounter {
     /// - `object`: Object being destroyed.
     /// - `quantity`: Quantity of the object in that tile (usize of the `Content` enum).
     ///
-    pub(crate) fn add_score_destroy(&self, object: &Content, quantity: usize) {
-        self.add_score_flat(*self.score_table.get(&object.to_default()).unwrap() * quantity as f32);
+    /// # Returns
+    ///
+    /// The score added.
+    ///
+    pub(crate) fn add_score_destroy(&self, object: &Content, quantity: usize) -> f32 {
+        let value = *self.score_table.get(&object.to_default()).unwrap() * quantity as f32;
+        self.add_score_flat(value);
+        value
     }
 
     /// "Add_score" function used inside of `put` interface
@@ -394,11 +399,17 @@ impl ScoreCounter {
     /// - `dispose`: Object being disposed.
     /// - `quantity`: Quantity of the object being disposed.
     ///
-    pub(crate) fn add_score_put(&self, dispose: &Content, quantity: usize) {
+    /// # Returns
+    ///
+    /// The score added.
+    ///
+    pub(crate) fn add_score_put(&self, dispose: &Content, quantity: usize) -> f32 {
         // if !self.score_table.contains_key(&dispose) {
         //     return Result::Err(MissingScoreTableEntry);
         // }
-        self.add_score_flat(*self.score_table.get(&dispose.to_default()).unwrap() * quantity as f32);
+        let value = *self.score_table.get(&dispose.to_default()).unwrap() * quantity as f32;
+        self.add_score_flat(value);
+        value
     }
 
     /// "Add_score" function used primarily inside of the `ScoreStruct` itself.
@@ -413,7 +424,7 @@ impl ScoreCounter {
     /// - `value`: An f32 to be added to the score.
     ///
     pub(crate) fn add_score_flat(&self, value: f32) {
-        self.score.clone().replace(self.score.clone().take() + value);
+        *self.score.borrow_mut() += value;
     }
 
     /// A getter function.
@@ -423,14 +434,14 @@ impl ScoreCounter {
     /// A result of an f32.
     ///
     pub(crate) fn get_score(&self) -> f32 {
-        *self.score.clone().borrow()
+        *self.score.borrow()
     }
 }
 
 impl Default for ScoreCounter {
     fn default() -> Self {
         ScoreCounter {
-            score: Rc::new(RefCell::new(0.)),
+            score: RefCell::new(0.),
             max_score: 1.,
             score_table: HashMap::new(),
         }
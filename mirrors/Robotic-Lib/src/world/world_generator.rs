@@ -1,4 +1,4 @@
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet, VecDeque};
 
 use crate::utils::LibError;
 use crate::utils::LibError::*;
@@ -20,6 +20,9 @@ use crate::world::tile::{Content, Tile, TileType};
 /// - `f32` - Max score of the world.
 /// - `Option<HashMap<Content, f32>> - optional score_table used in score.rs. If None is provided, uses default score_table.
 ///
+/// When more robots share the world, `gen_spawn_points` chooses where each one starts. The default
+/// implementation uses `spawn_points_near`.
+///
 /// # Usage
 ///
 /// ```
@@ -56,6 +59,46 @@ pub type World = (
 );
 pub trait Generator {
     fn gen(&mut self) -> World;
+
+    fn gen_spawn_points(&mut self, map: &[Vec<Tile>], spawn: (usize, usize), robots: usize) -> Vec<(usize, usize)> {
+        spawn_points_near(map, spawn, robots)
+    }
+}
+
+/// A function to find a spawn point for each robot.
+///
+/// The first robot spawns in `spawn`, the others on the closest walkable tiles reachable from it.
+///
+/// # Return
+/// - `Vec<(usize, usize)>` - The spawn points, fewer than `robots` if there aren't enough reachable tiles.
+pub fn spawn_points_near(map: &[Vec<Tile>], spawn: (usize, usize), robots: usize) -> Vec<(usize, usize)> {
+    let mut spawn_points = Vec::with_capacity(robots);
+    let mut visited = HashSet::from([spawn]);
+    let mut queue = VecDeque::from([spawn]);
+
+    while let Some((row, col)) = queue.pop_front() {
+        if spawn_points.len() == robots {
+            break;
+        }
+        spawn_points.push((row, col));
+
+        let neighbours = [
+            (row.wrapping_sub(1), col),
+            (row + 1, col),
+            (row, col.wrapping_sub(1)),
+            (row, col + 1),
+        ];
+        for (row, col) in neighbours {
+            if row < map.len()
+                && col < map.len()
+                && map[row][col].tile_type.properties().walk()
+                && visited.insert((row, col))
+            {
+                queue.push_back((row, col));
+            }
+        }
+    }
+    spawn_points
 }
 
 /// A function to check if the world is valid.
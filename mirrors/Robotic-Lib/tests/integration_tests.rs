@@ -3,18 +3,15 @@ use std::process::exit;
 
 use strum::IntoEnumIterator;
 
-use robotics_lib::energy::Energy;
 use robotics_lib::event::events::Event;
 use robotics_lib::interface::Direction::{Down, Left, Right, Up};
 use robotics_lib::interface::{
     craft, destroy, discover_tiles, go, one_direction_view, put, robot_map, robot_view, teleport, Direction, Tools,
 };
-use robotics_lib::runner::backpack::BackPack;
 use robotics_lib::runner::Runner;
-use robotics_lib::runner::{Robot, Runnable};
+use robotics_lib::runner::{Robot, RobotState, Runnable};
 use robotics_lib::utils::LibError;
 use robotics_lib::utils::LibError::NoContent;
-use robotics_lib::world::coordinates::Coordinate;
 use robotics_lib::world::environmental_conditions::EnvironmentalConditions;
 use robotics_lib::world::environmental_conditions::WeatherType;
 use robotics_lib::world::tile::Content;
@@ -130,6 +127,7 @@ fn test_woodworker_robot() {
     }
     let mut generator: WorldGenerator = WorldGenerator::new();
 
+    #[derive(RobotState)]
     struct MyRobot(Robot);
 
     impl Runnable for MyRobot {
@@ -305,25 +303,6 @@ fn test_woodworker_robot() {
                 | _ => {}
             }
         }
-
-        fn get_energy(&self) -> &Energy {
-            &self.0.energy
-        }
-        fn get_energy_mut(&mut self) -> &mut Energy {
-            &mut self.0.energy
-        }
-        fn get_coordinate(&self) -> &Coordinate {
-            &self.0.coordinate
-        }
-        fn get_coordinate_mut(&mut self) -> &mut Coordinate {
-            &mut self.0.coordinate
-        }
-        fn get_backpack(&self) -> &BackPack {
-            &self.0.backpack
-        }
-        fn get_backpack_mut(&mut self) -> &mut BackPack {
-            &mut self.0.backpack
-        }
     }
 
     let my_robot = MyRobot(Robot::new());
@@ -443,6 +422,7 @@ fn test_crafter_robot() {
     }
     let mut generator: WorldGenerator = WorldGenerator::new();
 
+    #[derive(RobotState)]
     struct MyRobot(Robot);
 
     impl Runnable for MyRobot {
@@ -548,25 +528,6 @@ fn test_crafter_robot() {
                 | _ => {}
             }
         }
-
-        fn get_energy(&self) -> &Energy {
-            &self.0.energy
-        }
-        fn get_energy_mut(&mut self) -> &mut Energy {
-            &mut self.0.energy
-        }
-        fn get_coordinate(&self) -> &Coordinate {
-            &self.0.coordinate
-        }
-        fn get_coordinate_mut(&mut self) -> &mut Coordinate {
-            &mut self.0.coordinate
-        }
-        fn get_backpack(&self) -> &BackPack {
-            &self.0.backpack
-        }
-        fn get_backpack_mut(&mut self) -> &mut BackPack {
-            &mut self.0.backpack
-        }
     }
 
     let my_robot = MyRobot(Robot::new());
@@ -662,6 +623,7 @@ fn test_robot_map() {
     }
     let mut generator: WorldGenerator = WorldGenerator::new();
 
+    #[derive(RobotState)]
     struct MyRobot(Robot);
 
     impl Runnable for MyRobot {
@@ -711,25 +673,6 @@ fn test_robot_map() {
                 | _ => {}
             }
         }
-
-        fn get_energy(&self) -> &Energy {
-            &self.0.energy
-        }
-        fn get_energy_mut(&mut self) -> &mut Energy {
-            &mut self.0.energy
-        }
-        fn get_coordinate(&self) -> &Coordinate {
-            &self.0.coordinate
-        }
-        fn get_coordinate_mut(&mut self) -> &mut Coordinate {
-            &mut self.0.coordinate
-        }
-        fn get_backpack(&self) -> &BackPack {
-            &self.0.backpack
-        }
-        fn get_backpack_mut(&mut self) -> &mut BackPack {
-            &mut self.0.backpack
-        }
     }
 
     let my_robot = MyRobot(Robot::new());
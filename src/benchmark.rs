@@ -0,0 +1,315 @@
+use std::collections::{BTreeMap, BTreeSet, VecDeque};
+use std::env;
+use std::fs;
+use std::process::Command;
+use std::time::Instant;
+use robotics_lib::world::tile::{Tile, TileType};
+use robotics_lib::world::world_generator::{get_content_percentage, get_tiletype_percentage, Generator};
+use serde::{Deserialize, Serialize};
+use crate::registry::Registry;
+use crate::world_file::build_world_generator;
+use crate::world_generator_parameters::default_parameters;
+
+//the line printed by a benchmark process with the statistics of its world
+const RESULT_PREFIX: &str = "BENCHMARK_RESULT";
+//midgard doesn't return its phase timings, it only prints them as "Profiler: <phase> <time> ms"
+const PROFILER_PREFIX: &str = "Profiler: ";
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum ReportFormat {
+    Json,
+    Csv,
+}
+
+pub struct BenchmarkOptions {
+    //empty means every registered generator
+    pub world_generators: Vec<String>,
+    pub sizes: Vec<usize>,
+    //worlds generated for each generator and size, with seeds 0..runs
+    pub runs: u64,
+    pub format: ReportFormat,
+    //None prints the report on stdout
+    pub output: Option<String>,
+}
+
+impl Default for BenchmarkOptions {
+    fn default() -> Self {
+        Self {
+            world_generators: vec![],
+            sizes: vec![100, 200],
+            runs: 3,
+            format: ReportFormat::Json,
+            output: None,
+        }
+    }
+}
+
+fn parse_list<T: std::str::FromStr>(value: &str, what: &str) -> Result<Vec<T>, String> {
+    value
+        .split(',')
+        .map(|item| item.trim().parse().map_err(|_| format!("invalid {what}: {item}")))
+        .collect()
+}
+
+impl BenchmarkOptions {
+    //parses "--generators a,b", "--sizes a,b", "--runs N", "--format json|csv" and "--output file",
+    //everything else is ignored
+    pub fn from_args(args: &[String]) -> Result<Self, String> {
+        let mut options = Self::default();
+        let mut args = args.iter();
+
+        while let Some(arg) = args.next() {
+            let mut value = || args.next().ok_or(format!("{arg} needs a value"));
+            match arg.as_str() {
+                "--generators" => options.world_generators = parse_list(value()?, "world generator")?,
+                "--sizes" => options.sizes = parse_list(value()?, "world size")?,
+                "--runs" => {
+                    let value = value()?;
+                    options.runs = value.parse().map_err(|_| format!("invalid run count: {value}"))?;
+                }
+                "--format" => {
+                    options.format = match value()?.as_str() {
+                        "json" => ReportFormat::Json,
+                        "csv" => ReportFormat::Csv,
+                        format => return Err(format!("unknown format: {format}, expected json or csv")),
+                    }
+                }
+                "--output" => options.output = Some(value()?.clone()),
+                _ => {}
+            }
+        }
+
+        Ok(options)
+    }
+}
+
+//what the benchmark process measures on the world it generated
+#[derive(Serialize, Deserialize)]
+struct WorldStats {
+    total_ms: u128,
+    tile_distribution: BTreeMap<String, f64>,
+    content_distribution: BTreeMap<String, f64>,
+    walkable_fraction: f64,
+    walkable_regions: usize,
+    teleports: usize,
+}
+
+#[derive(Serialize)]
+struct BenchmarkRun {
+    world_generator: String,
+    world_size: usize,
+    seed: u64,
+    #[serde(flatten)]
+    stats: WorldStats,
+    //only filled for the generators that print their phases, like midgard
+    phases_ms: BTreeMap<String, u128>,
+}
+
+//number of 4-connected groups of walkable tiles
+fn count_walkable_regions(map: &[Vec<Tile>]) -> usize {
+    let size = map.len();
+    let mut visited = vec![vec![false; size]; size];
+    let mut regions = 0;
+
+    for row in 0..size {
+        for col in 0..size {
+            if visited[row][col] || !map[row][col].tile_type.properties().walk() {
+                continue;
+            }
+            regions += 1;
+            visited[row][col] = true;
+
+            let mut queue = VecDeque::from([(row, col)]);
+            while let Some((row, col)) = queue.pop_front() {
+                let neighbours = [
+                    (row.wrapping_sub(1), col),
+                    (row + 1, col),
+                    (row, col.wrapping_sub(1)),
+                    (row, col + 1),
+                ];
+                for (row, col) in neighbours {
+                    if row < size && col < size && !visited[row][col] && map[row][col].tile_type.properties().walk() {
+                        visited[row][col] = true;
+                        queue.push_back((row, col));
+                    }
+                }
+            }
+        }
+    }
+
+    regions
+}
+
+fn world_stats(map: Vec<Vec<Tile>>, total_ms: u128) -> WorldStats {
+    let tiles = map.iter().flatten();
+    let walkable = tiles.clone().filter(|tile| tile.tile_type.properties().walk()).count();
+    let teleports = tiles.clone().filter(|tile| matches!(tile.tile_type, TileType::Teleport(_))).count();
+
+    WorldStats {
+        total_ms,
+        tile_distribution: get_tiletype_percentage(&map)
+            .into_iter()
+            .map(|(tile_type, percentage)| (format!("{tile_type:?}"), percentage))
+            .collect(),
+        content_distribution: get_content_percentage(&map)
+            .into_iter()
+            .map(|(content, percentage)| (content.to_string(), percentage))
+            .collect(),
+        walkable_fraction: walkable as f64 / tiles.count() as f64,
+        walkable_regions: count_walkable_regions(&map),
+        teleports,
+    }
+}
+
+//generates a single world in the current process.
+//runs in its own process like the headless games, so the phase timings printed by the generator
+//can be told apart from the ones of other worlds
+fn run_world(registry: &Registry, world_generator_selection: &str, world_size: usize, seed: u64) -> Result<String, String> {
+    let mut params = default_parameters();
+    params.world_size = world_size;
+    params.seed = seed;
+    let mut world_generator = build_world_generator(registry, world_generator_selection, &params)?;
+
+    let start = Instant::now();
+    let (map, ..) = world_generator.gen();
+    let stats = world_stats(map, start.elapsed().as_millis());
+    serde_json::to_string(&stats).map_err(|error| error.to_string())
+}
+
+//the benchmark process started by spawn_world, with "<generator> <world size> <seed>" as arguments.
+//The statistics or the error are printed on stdout, the error is also returned so the process can
+//exit with a failure status
+pub fn world_process(registry: &Registry, args: &[String]) -> Result<(), String> {
+    let result = match args {
+        [world_generator_selection, world_size, seed] => world_size
+            .parse()
+            .map_err(|_| format!("invalid world size: {world_size}"))
+            .and_then(|world_size| {
+                let seed = seed.parse().map_err(|_| format!("invalid seed: {seed}"))?;
+                run_world(registry, world_generator_selection, world_size, seed)
+            }),
+        _ => Err("expected a world generator, a world size and a seed".to_string()),
+    };
+
+    match &result {
+        Ok(json) => println!("{RESULT_PREFIX}\t{json}"),
+        Err(error) => println!("{RESULT_PREFIX}\terror: {error}"),
+    }
+    result.map(|_| ())
+}
+
+fn spawn_world(world_generator_selection: &str, world_size: usize, seed: u64) -> Result<(WorldStats, BTreeMap<String, u128>), String> {
+    let executable = env::current_exe().map_err(|error| error.to_string())?;
+    let output = Command::new(executable)
+        .args(["--benchmark-world", world_generator_selection, &world_size.to_string(), &seed.to_string()])
+        .output()
+        .map_err(|error| error.to_string())?;
+    let stdout = String::from_utf8_lossy(&output.stdout);
+
+    let mut phases_ms = BTreeMap::new();
+    for line in stdout.lines() {
+        let Some(phase) = line.strip_prefix(PROFILER_PREFIX) else {
+            continue;
+        };
+        //"<phase name> <time> ms", the phase name can contain spaces
+        let mut words = phase.rsplitn(3, ' ');
+        if let (Some("ms"), Some(time), Some(name)) = (words.next(), words.next(), words.next()) {
+            if let Ok(time) = time.parse() {
+                phases_ms.insert(name.to_string(), time);
+            }
+        }
+    }
+
+    let result = stdout
+        .lines()
+        .find_map(|line| line.strip_prefix(RESULT_PREFIX))
+        .map(|result| result.trim_start_matches('\t'))
+        //no result line means the benchmark process crashed
+        .ok_or(format!("generator crashed ({})", output.status))?;
+    if let Some(error) = result.strip_prefix("error: ") {
+        return Err(error.to_string());
+    }
+
+    let stats = serde_json::from_str(result).map_err(|error| error.to_string())?;
+    Ok((stats, phases_ms))
+}
+
+fn csv_report(runs: &[BenchmarkRun]) -> String {
+    //every run can have different phases, tiles and contents, the columns are the union of all of them
+    let phases: BTreeSet<&String> = runs.iter().flat_map(|run| run.phases_ms.keys()).collect();
+    let tiles: BTreeSet<&String> = runs.iter().flat_map(|run| run.stats.tile_distribution.keys()).collect();
+    let contents: BTreeSet<&String> = runs.iter().flat_map(|run| run.stats.content_distribution.keys()).collect();
+
+    let mut header = vec![
+        "world_generator".to_string(),
+        "world_size".to_string(),
+        "seed".to_string(),
+        "total_ms".to_string(),
+        "walkable_fraction".to_string(),
+        "walkable_regions".to_string(),
+        "teleports".to_string(),
+    ];
+    header.extend(phases.iter().map(|phase| format!("phase_ms:{phase}")));
+    header.extend(tiles.iter().map(|tile| format!("tile:{tile}")));
+    header.extend(contents.iter().map(|content| format!("content:{content}")));
+
+    let mut lines = vec![header.join(",")];
+    for run in runs {
+        let mut columns = vec![
+            run.world_generator.clone(),
+            run.world_size.to_string(),
+            run.seed.to_string(),
+            run.stats.total_ms.to_string(),
+            run.stats.walkable_fraction.to_string(),
+            run.stats.walkable_regions.to_string(),
+            run.stats.teleports.to_string(),
+        ];
+        columns.extend(phases.iter().map(|phase| run.phases_ms.get(*phase).map(u128::to_string).unwrap_or_default()));
+        columns.extend(tiles.iter().map(|tile| run.stats.tile_distribution.get(*tile).unwrap_or(&0.0).to_string()));
+        columns.extend(contents.iter().map(|content| run.stats.content_distribution.get(*content).unwrap_or(&0.0).to_string()));
+        lines.push(columns.join(","));
+    }
+
+    lines.join("\n") + "\n"
+}
+
+pub fn run_benchmark(registry: &Registry, options: &BenchmarkOptions) -> Result<(), String> {
+    let world_generators = if options.world_generators.is_empty() {
+        registry.world_generators().iter().map(|entry| entry.name.to_string()).collect()
+    } else {
+        options.world_generators.clone()
+    };
+
+    let mut runs = Vec::new();
+    for world_generator in &world_generators {
+        for world_size in &options.sizes {
+            for seed in 0..options.runs {
+                //the report goes on stdout, the progress on stderr
+                eprintln!("Generating {world_generator} {world_size}x{world_size} seed {seed}");
+                match spawn_world(world_generator, *world_size, seed) {
+                    Ok((stats, phases_ms)) => runs.push(BenchmarkRun {
+                        world_generator: world_generator.clone(),
+                        world_size: *world_size,
+                        seed,
+                        stats,
+                        phases_ms,
+                    }),
+                    Err(error) => eprintln!("Error generating {world_generator} {world_size}x{world_size} seed {seed}: {error}"),
+                }
+            }
+        }
+    }
+
+    let report = match options.format {
+        ReportFormat::Json => serde_json::to_string_pretty(&runs).map_err(|error| error.to_string())? + "\n",
+        ReportFormat::Csv => csv_report(&runs),
+    };
+
+    match &options.output {
+        Some(path) => fs::write(path, report).map_err(|error| format!("Error writing {path}: {error}")),
+        None => {
+            print!("{report}");
+            Ok(())
+        }
+    }
+}
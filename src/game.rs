@@ -0,0 +1,142 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+use macroquad::prelude::Conf;
+use macroquad::Window;
+use olympus::channel::Channel;
+use olympus::Visualizer;
+use ragnarok::GuiRunner;
+use robotics_lib::runner::Runnable;
+use robotics_lib::utils::LibError;
+use robotics_lib::world::world_generator::{check_world, Generator, World};
+use crate::limited_robot::LimitedRobot;
+use crate::profile::Profile;
+use crate::registry::{Registry, RobotEntry};
+use crate::replay::{RecordingRobot, Replay};
+use crate::terminal_visualizer::TerminalVisualizer;
+use crate::world_file::build_world_generator;
+
+//a world generated ahead of time, so it can be checked before a visualizer is opened and then
+//handed to it without generating it again
+pub struct PregeneratedWorld {
+    world: World,
+}
+
+impl PregeneratedWorld {
+    pub fn new(generator: &mut impl Generator) -> Self {
+        Self { world: generator.gen() }
+    }
+
+    pub fn max_score(&self) -> f32 {
+        self.world.3
+    }
+
+    //the checks Runner::new does, for the visualizers that create the runner by themselves
+    pub fn check(&self) -> Result<(), LibError> {
+        let (map, (row, col), ..) = &self.world;
+        check_world(map)?;
+        if *row >= map.len() || *col >= map.len() {
+            return Err(LibError::OutOfBounds);
+        }
+        Ok(())
+    }
+}
+
+impl Generator for PregeneratedWorld {
+    fn gen(&mut self) -> World { self.world.clone() }
+}
+
+//a game that is ready to be run once the launcher gives back the terminal
+pub enum Game {
+    Olympus {
+        robot: Box<dyn Runnable>,
+        world: PregeneratedWorld,
+        world_size: usize,
+        channel: Rc<RefCell<Channel>>,
+    },
+    Ragnarok(GuiRunner),
+    Terminal(TerminalVisualizer),
+}
+
+fn olympus_window_conf() -> Conf {
+    Conf {
+        window_title: "Olympus".to_string(),
+        window_width: 1920,
+        window_height: 1080,
+        fullscreen: false,
+        ..Default::default()
+    }
+}
+
+//the robot of the profile, recorded if the profile asks for it and stopped after the run length.
+//tick_speed is only enforced by the wrapper for the visualizers that can't pace the ticks themselves
+fn build_robot(robot_entry: &RobotEntry, channel: Rc<RefCell<Channel>>, profile: &Profile, tick_speed: Option<f32>) -> Result<Box<dyn Runnable>, String> {
+    let mut robot = robot_entry.build(channel)?;
+    if let Some(record_file) = &profile.record_file {
+        robot = Box::new(RecordingRobot::new(robot, record_file)?);
+    }
+    Ok(Box::new(LimitedRobot::new(robot, tick_speed, profile.run_length)))
+}
+
+//builds everything the game needs, so that invalid settings and the errors of Runner::new are
+//reported before the visualizer takes over
+pub fn prepare_game(registry: &Registry, profile: &Profile) -> Result<Game, String> {
+    let robot_entry = registry.robot(&profile.robot).ok_or(format!("Unknown robot: {}", profile.robot))?;
+    let mut world_generator = build_world_generator(registry, &profile.world_generator, &profile.world_generator_parameters)?;
+    let mut world = PregeneratedWorld::new(&mut world_generator);
+
+    //robots need to have a channel for compatibility with olympus
+    let channel = Rc::new(RefCell::new(Channel::default()));
+    let start_error = |error: LibError| format!("Error starting the game: {error:?}");
+
+    match profile.visualizer.as_str() {
+        "oly" => {
+            //olympus creates the runner by itself and panics if it fails, so the world is checked here first
+            world.check().map_err(start_error)?;
+            //olympus paces the ticks by itself
+            let robot = build_robot(robot_entry, Rc::clone(&channel), profile, None)?;
+            let world_size = profile.world_generator_parameters.world_size;
+            Ok(Game::Olympus { robot, world, world_size, channel })
+        }
+        "rag" => {
+            let robot = build_robot(robot_entry, channel, profile, profile.tick_speed)?;
+            let visualizer = GuiRunner::new(robot, &mut world).map_err(start_error)?;
+            Ok(Game::Ragnarok(visualizer))
+        }
+        "term" => {
+            //the terminal visualizer paces the ticks by itself and lets the user change the speed
+            let robot = build_robot(robot_entry, channel, profile, None)?;
+            let visualizer = TerminalVisualizer::new(robot, &mut world, profile.tick_speed).map_err(start_error)?;
+            Ok(Game::Terminal(visualizer))
+        }
+        visualizer => Err(format!("Unknown visualizer: {visualizer}")),
+    }
+}
+
+//replays are played back in the terminal visualizer, olympus and ragnarok can only show a game
+//while a Runner plays it
+pub fn prepare_replay(path: &str) -> Result<Game, String> {
+    let replay = Replay::load(path)?;
+    if replay.ticks.is_empty() {
+        return Err(format!("{path} has no recorded ticks"));
+    }
+    Ok(Game::Terminal(TerminalVisualizer::replay(replay, None)))
+}
+
+impl Game {
+    pub fn run(self) -> Result<(), String> {
+        match self {
+            Game::Olympus { robot, world, world_size, channel } => {
+                Window::from_config(olympus_window_conf(), async move {
+                    let mut visualizer = Visualizer::new(robot, world, world_size, channel);
+                    visualizer.start().await
+                });
+                Ok(())
+            }
+            Game::Ragnarok(visualizer) => visualizer.run().map_err(|error| format!("Error running ragnarok: {error:?}")),
+            Game::Terminal(visualizer) => {
+                visualizer.run();
+                Ok(())
+            }
+        }
+    }
+}
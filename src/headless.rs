@@ -0,0 +1,277 @@
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::env;
+use std::process::Command;
+use std::rc::Rc;
+use olympus::channel::Channel;
+use robotics_lib::interface::{get_score, robot_map};
+use robotics_lib::runner::Runner;
+use robotics_lib::world::tile::Content;
+use crate::game::PregeneratedWorld;
+use crate::observed_robot::ObservedRobot;
+use crate::registry::Registry;
+use crate::world_file::{build_world_generator, WORLD_FILE_PREFIX};
+use crate::world_generator_parameters::default_parameters;
+
+//every line printed by a game process that starts with this is a result, everything else is noise
+//coming from the robots and the world generators
+const RESULT_PREFIX: &str = "HEADLESS_RESULT";
+
+pub struct BatchOptions {
+    pub ticks: usize,
+    pub seeds: Vec<u64>,
+    //empty means every registered robot that doesn't need to be set up first
+    pub robots: Vec<String>,
+    //saved worlds played next to the registered generators
+    pub world_files: Vec<String>,
+}
+
+impl Default for BatchOptions {
+    fn default() -> Self {
+        Self {
+            ticks: 1000,
+            seeds: vec![0, 1, 2],
+            robots: vec![],
+            world_files: vec![],
+        }
+    }
+}
+
+impl BatchOptions {
+    //parses "--ticks N", "--seeds a,b,c", "--robots a,b" and any number of "--world file"
+    pub fn from_args(args: &[String]) -> Result<Self, String> {
+        let mut options = Self::default();
+        let mut args = args.iter();
+
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--ticks" => {
+                    let value = args.next().ok_or("--ticks needs a value")?;
+                    options.ticks = value.parse().map_err(|_| format!("invalid tick count: {value}"))?;
+                }
+                "--seeds" => {
+                    let value = args.next().ok_or("--seeds needs a value")?;
+                    options.seeds = value
+                        .split(',')
+                        .map(|seed| seed.trim().parse().map_err(|_| format!("invalid seed: {seed}")))
+                        .collect::<Result<_, _>>()?;
+                }
+                "--robots" => {
+                    let value = args.next().ok_or("--robots needs a value")?;
+                    options.robots = value.split(',').map(|name| name.trim().to_string()).collect();
+                }
+                "--world" => {
+                    let value = args.next().ok_or("--world needs a file")?;
+                    options.world_files.push(value.clone());
+                }
+                //a mistyped option would otherwise quietly run with the defaults
+                _ => return Err(format!("unknown option: {arg}")),
+            }
+        }
+
+        Ok(options)
+    }
+}
+
+#[derive(Default)]
+struct GameStats {
+    score: f32,
+    energy: usize,
+    energy_consumed: usize,
+    backpack: HashMap<Content, usize>,
+    discovered_tiles: usize,
+}
+
+//the result of a game, as printed by the game process
+pub struct GameResult {
+    pub score: f32,
+    pub max_score: f32,
+    pub energy: usize,
+    pub energy_consumed: usize,
+    pub discovered_tiles: usize,
+    //"content:amount" pairs separated by commas, "-" when empty
+    pub backpack: String,
+}
+
+impl GameResult {
+    fn to_line(&self) -> String {
+        format!(
+            "{:.2}\t{:.2}\t{}\t{}\t{}\t{}",
+            self.score, self.max_score, self.energy, self.energy_consumed, self.discovered_tiles, self.backpack
+        )
+    }
+
+    fn from_line(line: &str) -> Result<Self, String> {
+        let invalid = || format!("invalid game result: {line}");
+        let columns = line.split('\t').collect::<Vec<_>>();
+        let [score, max_score, energy, energy_consumed, discovered_tiles, backpack] = columns[..] else {
+            return Err(invalid());
+        };
+
+        Ok(Self {
+            score: score.parse().map_err(|_| invalid())?,
+            max_score: max_score.parse().map_err(|_| invalid())?,
+            energy: energy.parse().map_err(|_| invalid())?,
+            energy_consumed: energy_consumed.parse().map_err(|_| invalid())?,
+            discovered_tiles: discovered_tiles.parse().map_err(|_| invalid())?,
+            backpack: backpack.to_string(),
+        })
+    }
+}
+
+//runs a single game in the current process.
+//robotics_lib keeps the explored tiles in a process-wide list, so every game must run in its own
+//process, otherwise the discovered tiles of a game would leak into the next one
+fn run_game(registry: &Registry, robot_selection: &str, world_generator_selection: &str, seed: u64, ticks: usize) -> Result<GameResult, String> {
+    let robot_entry = registry.robot(robot_selection).ok_or("unknown robot")?;
+
+    let channel = Rc::new(RefCell::new(Channel::default()));
+    let robot = robot_entry.build(channel)?;
+    let stats = Rc::new(RefCell::new(GameStats::default()));
+    let robot = ObservedRobot::new(robot, {
+        let stats = Rc::clone(&stats);
+        move |robot, world, energy_consumed| {
+            let mut stats = stats.borrow_mut();
+            stats.score = get_score(world);
+            stats.energy = robot.get_energy().get_energy_level();
+            stats.energy_consumed += energy_consumed;
+            stats.backpack = robot.get_backpack().get_contents().clone();
+            stats.discovered_tiles = robot_map(world)
+                .map(|map| map.iter().flatten().filter(|tile| tile.is_some()).count())
+                .unwrap_or(0);
+        }
+    });
+    let mut params = default_parameters();
+    params.seed = seed;
+    let mut world_generator = build_world_generator(registry, world_generator_selection, &params)?;
+
+    //generated ahead of time to know the max score of the world
+    let mut world = PregeneratedWorld::new(&mut world_generator);
+    let mut runner = Runner::new(Box::new(robot), &mut world).map_err(|error| format!("{error:?}"))?;
+    for _ in 0..ticks {
+        runner.game_tick().map_err(|error| format!("{error:?}"))?;
+    }
+
+    let stats = stats.borrow();
+    let mut backpack = stats.backpack
+        .iter()
+        .filter(|(_, amount)| **amount > 0)
+        .map(|(content, amount)| format!("{content}:{amount}"))
+        .collect::<Vec<_>>();
+    backpack.sort();
+    let backpack = if backpack.is_empty() { "-".to_string() } else { backpack.join(",") };
+
+    Ok(GameResult {
+        score: stats.score,
+        max_score: world.max_score(),
+        energy: stats.energy,
+        energy_consumed: stats.energy_consumed,
+        discovered_tiles: stats.discovered_tiles,
+        backpack,
+    })
+}
+
+//the game process started by spawn_game, with "<robot> <generator> <seed> <ticks>" as arguments.
+//The result or the error is printed on stdout, the error is also returned so the process can exit
+//with a failure status
+pub fn game_process(registry: &Registry, args: &[String]) -> Result<(), String> {
+    let result = match args {
+        [robot_selection, world_generator_selection, seed, ticks] => seed
+            .parse()
+            .map_err(|_| format!("invalid seed: {seed}"))
+            .and_then(|seed| {
+                let ticks = ticks.parse().map_err(|_| format!("invalid tick count: {ticks}"))?;
+                run_game(registry, robot_selection, world_generator_selection, seed, ticks)
+            }),
+        _ => Err("expected a robot, a world generator, a seed and a tick count".to_string()),
+    };
+
+    match &result {
+        Ok(result) => println!("{RESULT_PREFIX}\t{}", result.to_line()),
+        Err(error) => println!("{RESULT_PREFIX}\terror: {error}"),
+    }
+    result.map(|_| ())
+}
+
+//runs a game in a new process, see run_game
+pub fn spawn_game(robot_selection: &str, world_generator_selection: &str, seed: u64, ticks: usize) -> Result<GameResult, String> {
+    let executable = env::current_exe().map_err(|error| error.to_string())?;
+
+    let output = Command::new(executable)
+        .args([
+            "--headless-game",
+            robot_selection,
+            world_generator_selection,
+            &seed.to_string(),
+            &ticks.to_string(),
+        ])
+        .output()
+        .map_err(|error| error.to_string())?;
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let result = stdout
+        .lines()
+        .find_map(|line| line.strip_prefix(RESULT_PREFIX))
+        .map(|result| result.trim_start_matches('\t'))
+        //no result line means the game process crashed
+        .ok_or(format!("game crashed ({})", output.status))?;
+    if let Some(error) = result.strip_prefix("error: ") {
+        return Err(error.to_string());
+    }
+
+    GameResult::from_line(result)
+}
+
+//plays every game of the batch and prints a row for each one, the error tells how many games failed
+pub fn run_batch(registry: &Registry, options: &BatchOptions) -> Result<(), String> {
+    let robots: Vec<String> = if options.robots.is_empty() {
+        registry.robots().iter().filter(|entry| !entry.needs_setup).map(|entry| entry.name.to_string()).collect()
+    } else {
+        options.robots.clone()
+    };
+    let world_generators = registry
+        .world_generators()
+        .iter()
+        .map(|entry| entry.name.to_string())
+        .chain(options.world_files.iter().map(|path| format!("{WORLD_FILE_PREFIX}{path}")))
+        .collect::<Vec<_>>();
+    let games = robots.len() * world_generators.len() * options.seeds.len();
+
+    println!("Running {games} games of {} ticks each", options.ticks);
+    println!();
+    println!(
+        "{:<6} {:<10} {:>20} {:>10} {:>10} {:>8} {:>8} {:>10}  {}",
+        "robot", "generator", "seed", "score", "max score", "energy", "consumed", "discovered", "backpack"
+    );
+
+    let mut failed = 0;
+    for robot in &robots {
+        for world_generator in &world_generators {
+            for seed in &options.seeds {
+                match spawn_game(robot, world_generator, *seed, options.ticks) {
+                    Ok(result) => println!(
+                        "{:<6} {:<10} {:>20} {:>10.2} {:>10.2} {:>8} {:>8} {:>10}  {}",
+                        robot,
+                        world_generator,
+                        seed,
+                        result.score,
+                        result.max_score,
+                        result.energy,
+                        result.energy_consumed,
+                        result.discovered_tiles,
+                        result.backpack
+                    ),
+                    Err(error) => {
+                        failed += 1;
+                        println!("{:<6} {:<10} {:>20} error: {}", robot, world_generator, seed, error);
+                    }
+                }
+            }
+        }
+    }
+
+    if failed > 0 {
+        return Err(format!("{failed} of {games} games failed"));
+    }
+    Ok(())
+}
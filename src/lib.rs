@@ -0,0 +1,17 @@
+pub mod benchmark;
+pub mod game;
+pub mod headless;
+pub mod limited_robot;
+pub mod observed_robot;
+pub mod process_robot;
+pub mod profile;
+pub mod registry;
+pub mod replay;
+pub mod script_robot;
+pub mod swift_seller_demo;
+pub mod terminal_visualizer;
+#[cfg(test)]
+mod test_world;
+pub mod tournament;
+pub mod world_file;
+pub mod world_generator_parameters;
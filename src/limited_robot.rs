@@ -0,0 +1,60 @@
+use std::thread;
+use std::time::{Duration, Instant};
+use robotics_lib::energy::Energy;
+use robotics_lib::event::events::Event;
+use robotics_lib::runner::backpack::BackPack;
+use robotics_lib::runner::Runnable;
+use robotics_lib::world::coordinates::Coordinate;
+use robotics_lib::world::World;
+
+//wraps a robot to enforce the tick speed and the run length of a profile, for the visualizers that
+//don't have a setting for them.
+//once the run length is reached the robot stops acting but the game keeps running, so the
+//visualizer can still be used to look at the world
+pub struct LimitedRobot {
+    robot: Box<dyn Runnable>,
+    ticks: usize,
+    run_length: Option<usize>,
+    min_tick_duration: Option<Duration>,
+    last_tick: Instant,
+}
+
+impl LimitedRobot {
+    //tick_speed is the maximum number of ticks per second
+    pub fn new(robot: Box<dyn Runnable>, tick_speed: Option<f32>, run_length: Option<usize>) -> Self {
+        Self {
+            robot,
+            ticks: 0,
+            run_length,
+            min_tick_duration: tick_speed.map(|tick_speed| Duration::from_secs_f32(1.0 / tick_speed)),
+            last_tick: Instant::now(),
+        }
+    }
+}
+
+impl Runnable for LimitedRobot {
+    fn process_tick(&mut self, world: &mut World) {
+        if let Some(min_tick_duration) = self.min_tick_duration {
+            let elapsed = self.last_tick.elapsed();
+            if elapsed < min_tick_duration {
+                thread::sleep(min_tick_duration - elapsed);
+            }
+            self.last_tick = Instant::now();
+        }
+
+        if self.run_length.is_some_and(|run_length| self.ticks >= run_length) {
+            return;
+        }
+        self.ticks += 1;
+
+        self.robot.process_tick(world);
+    }
+
+    fn handle_event(&mut self, event: Event) { self.robot.handle_event(event) }
+    fn get_energy(&self) -> &Energy { self.robot.get_energy() }
+    fn get_energy_mut(&mut self) -> &mut Energy { self.robot.get_energy_mut() }
+    fn get_coordinate(&self) -> &Coordinate { self.robot.get_coordinate() }
+    fn get_coordinate_mut(&mut self) -> &mut Coordinate { self.robot.get_coordinate_mut() }
+    fn get_backpack(&self) -> &BackPack { self.robot.get_backpack() }
+    fn get_backpack_mut(&mut self) -> &mut BackPack { self.robot.get_backpack_mut() }
+}
@@ -1,15 +1,20 @@
-use std::cell::RefCell;
-use std::process::Command;
-use std::rc::Rc;
-use ai::MyRobot;
-use bmo::BMO;
+use std::env;
+use std::process;
+use std::str::FromStr;
+use advanced_programming_2024_demo::benchmark;
+use advanced_programming_2024_demo::game::{self, Game};
+use advanced_programming_2024_demo::headless;
+use advanced_programming_2024_demo::profile::{self, Profile};
+use advanced_programming_2024_demo::registry::Registry;
+use advanced_programming_2024_demo::swift_seller_demo;
+use advanced_programming_2024_demo::tournament;
+use advanced_programming_2024_demo::world_file;
+use advanced_programming_2024_demo::world_generator_parameters::{self, default_parameters, CONTENTS_RADII_FIELDS};
 use cursive::Cursive;
-use cursive::views::{Dialog, DummyView, LinearLayout, RadioGroup, TextView};
+use cursive::traits::{Nameable, Resizable, Scrollable};
+use cursive::views::{Checkbox, Dialog, DummyView, EditView, LinearLayout, ListView, RadioGroup, SelectView, TextView};
 use midgard::params::WorldGeneratorParameters;
 use midgard::{WorldGenerator, WorldVisualizer};
-use olympus::channel::Channel;
-use rip_worldgenerator::MyWorldGen;
-use robotics_lib::runner::Runnable;
 use robotics_lib::world::world_generator::Generator;
 
 fn start_midgard_visualizer(s: &mut Cursive) {
@@ -28,12 +33,10 @@ fn start_midgard_visualizer(s: &mut Cursive) {
 }
 
 fn start_swift_seller_demo(s: &mut Cursive) {
-    s.quit();
-
-    let command = "target/release/examples/swift_seller_demo";
-    Command::new(command)
-        .output()
-        .expect("Error running swift_seller demo");
+    match swift_seller_demo::prepare_game() {
+        Ok(game) => start_game_after_quit(s, game),
+        Err(error) => s.add_layer(Dialog::info(error)),
+    }
 }
 
 fn open_game_settings(s: &mut Cursive) {
@@ -45,18 +48,23 @@ fn open_game_settings(s: &mut Cursive) {
     let world_generator_text = TextView::new("World Generator:");
     let visualizer_text = TextView::new("Visualizer:");
 
-    let robot_layout = LinearLayout::vertical()
-        .child(robot_text)
-        .child(robot_radio.button("ai", "ai"))
-        .child(robot_radio.button("bmo", "bmo"));
-    let world_generator_layout = LinearLayout::vertical()
-        .child(world_generator_text)
-        .child(world_generator_radio.button("rip", "rip_worldgenerator"))
-        .child(world_generator_radio.button("mid", "midgard"));
+    let registry = Registry::default();
+
+    let mut robot_layout = LinearLayout::vertical()
+        .child(robot_text);
+    for entry in registry.robots() {
+        robot_layout.add_child(robot_radio.button(entry.name, entry.description));
+    }
+    let mut world_generator_layout = LinearLayout::vertical()
+        .child(world_generator_text);
+    for entry in registry.world_generators() {
+        world_generator_layout.add_child(world_generator_radio.button(entry.name, entry.description));
+    }
     let visualizer_layout = LinearLayout::vertical()
         .child(visualizer_text)
         .child(visualizer_radio.button("oly", "olympus"))
-        .child(visualizer_radio.button("rag", "ragnarok"));
+        .child(visualizer_radio.button("rag", "ragnarok"))
+        .child(visualizer_radio.button("term", "terminal"));
 
     s.pop_layer();
     s.add_layer(
@@ -70,8 +78,8 @@ fn open_game_settings(s: &mut Cursive) {
             .child(DummyView)
             .child(visualizer_layout)
         )
-        .button("Confirm", move |s| {
-                start_game(
+        .button("Next", move |s| {
+                open_world_generator_settings(
                     s,
                     robot_radio.selection().to_string(),
                     world_generator_radio.selection().to_string(),
@@ -82,92 +90,345 @@ fn open_game_settings(s: &mut Cursive) {
     );
 }
 
-fn start_game(s: &mut Cursive, robot_selection: String, world_generator_selection: String, visualizer_selection: String) {
-    s.quit();
+//checkbox to enable the parameter next to its value
+fn optional_parameter_view(name: &'static str, value: Option<f64>) -> LinearLayout {
+    LinearLayout::horizontal()
+        .child(Checkbox::new().with_checked(value.is_some()).with_name(format!("{name}_enabled")))
+        .child(DummyView)
+        .child(EditView::new().content(value.unwrap_or(1.0).to_string()).with_name(name).fixed_width(10))
+}
 
-    //robots need to have a channel for compatibility with olympus
-    let channel = Rc::new(RefCell::new(Channel::default()));
-    let robot = get_robot(robot_selection.clone(), Rc::clone(&channel));
-    let (mut world_generator, _world_size) = get_world_generator(world_generator_selection.clone());
+fn parse_parameter<T: FromStr>(s: &mut Cursive, name: &str) -> Result<T, String> {
+    let text = s
+        .call_on_name(name, |view: &mut EditView| view.get_content().to_string())
+        .unwrap_or_default();
+    text.trim().parse().map_err(|_| format!("Invalid value for {name}: \"{text}\""))
+}
+
+fn is_checked(s: &mut Cursive, name: &str) -> bool {
+    s.call_on_name(name, |view: &mut Checkbox| view.is_checked()).unwrap_or(false)
+}
 
-    let visualizer_selection = visualizer_selection.to_string();
-    if visualizer_selection == "oly" {
-        start_olympus_visualizer(robot_selection.clone(), world_generator_selection.clone());
-    } else if visualizer_selection == "rag" {
-        let visualizer = ragnarok::GuiRunner::new(robot, &mut  world_generator).expect("Error during ragnarok creation");
-        visualizer.run().expect("Error running ragnarok")
+fn parse_optional_parameter(s: &mut Cursive, name: &str) -> Result<Option<f64>, String> {
+    if is_checked(s, &format!("{name}_enabled")) {
+        parse_parameter(s, name).map(Some)
+    } else {
+        Ok(None)
+    }
+}
+
+fn read_world_generator_parameters(s: &mut Cursive) -> Result<WorldGeneratorParameters, String> {
+    let mut params = default_parameters();
+
+    params.seed = parse_parameter(s, "seed")?;
+    params.world_size = parse_parameter(s, "world_size")?;
+    params.always_sunny = is_checked(s, "always_sunny");
+    params.amount_of_rivers = parse_optional_parameter(s, "rivers")?;
+    params.amount_of_streets = parse_optional_parameter(s, "streets")?;
+    params.amount_of_teleports = parse_optional_parameter(s, "teleports")?;
+    params.elevation_multiplier = parse_optional_parameter(s, "elevation_multiplier")?;
+    params.starting_hour = parse_parameter(s, "starting_hour")?;
+    for field in &CONTENTS_RADII_FIELDS {
+        (field.set)(&mut params.contents_radii, parse_parameter(s, field.name)?);
     }
+
+    world_generator_parameters::validate(&params)?;
+    Ok(params)
 }
 
-fn get_robot(selection: String, channel: Rc<RefCell<Channel>>) -> Box<dyn Runnable> {
-    let robot = if selection == "bmo" {
-        Box::new(
-            BMO::new(channel)
-        ) as Box::<dyn Runnable>
+fn read_profile(s: &mut Cursive, robot_selection: &str, world_generator_selection: &str, visualizer_selection: &str) -> Result<Profile, String> {
+    let tick_speed = parse_optional_parameter(s, "tick_speed")?;
+    if tick_speed.is_some_and(|tick_speed| tick_speed <= 0.0) {
+        return Err("Tick speed must be greater than 0".to_string());
+    }
+    let run_length = if is_checked(s, "run_length_enabled") {
+        Some(parse_parameter(s, "run_length")?)
     } else {
-        Box::new(
-            MyRobot::new(channel)
-        ) as Box::<dyn Runnable>
+        None
+    };
+    let record_file = if is_checked(s, "record_file_enabled") {
+        let record_file: String = parse_parameter(s, "record_file")?;
+        if record_file.is_empty() {
+            return Err("The replay file needs a name".to_string());
+        }
+        Some(record_file)
+    } else {
+        None
     };
 
-    robot
+    Ok(Profile {
+        robot: robot_selection.to_string(),
+        world_generator: world_generator_selection.to_string(),
+        visualizer: visualizer_selection.to_string(),
+        world_generator_parameters: read_world_generator_parameters(s)?,
+        tick_speed: tick_speed.map(|tick_speed| tick_speed as f32),
+        run_length,
+        record_file,
+    })
 }
 
-//used for getting a impl Generator from a dyn Generator
-struct GeneratorWrapper {
-    generator: Box<dyn Generator>
-}
-impl Generator for GeneratorWrapper {
-    fn gen(&mut self) -> robotics_lib::world::world_generator::World { self.generator.gen() }
-}
-impl GeneratorWrapper {
-    pub fn new(generator: Box<dyn Generator>) -> Self { Self { generator } }
+fn open_save_profile(s: &mut Cursive, profile: Profile) {
+    s.add_layer(
+        Dialog::new()
+        .title("Save profile")
+        .content(
+            LinearLayout::vertical()
+            .child(TextView::new("Profile name:"))
+            .child(EditView::new().with_name("profile_name").fixed_width(30))
+        )
+        .button("Cancel", |s| { s.pop_layer(); })
+        .button("Save", move |s| {
+                let name = s
+                    .call_on_name("profile_name", |view: &mut EditView| view.get_content().trim().to_string())
+                    .unwrap_or_default();
+                if name.is_empty() {
+                    s.add_layer(Dialog::info("The profile needs a name"));
+                    return;
+                }
+
+                s.pop_layer();
+                match profile::save_profile(&name, &profile) {
+                    Ok(()) => s.add_layer(Dialog::info(format!("Profile \"{name}\" saved in {}", profile::PROFILES_FILE))),
+                    Err(error) => s.add_layer(Dialog::info(error)),
+                }
+            }
+        )
+    );
 }
 
-fn get_world_generator(selection: String) -> (GeneratorWrapper, usize) {
-    let world_size = 200;
+fn open_watch_replay(s: &mut Cursive) {
+    s.add_layer(
+        Dialog::new()
+        .title("Watch replay")
+        .content(
+            LinearLayout::vertical()
+            .child(TextView::new("Replay file:"))
+            .child(EditView::new().content("game.replay").with_name("replay_file").fixed_width(30))
+        )
+        .button("Cancel", |s| { s.pop_layer(); })
+        .button("Watch", |s| {
+                let path: String = s
+                    .call_on_name("replay_file", |view: &mut EditView| view.get_content().trim().to_string())
+                    .unwrap_or_default();
+                match game::prepare_replay(&path) {
+                    Ok(game) => start_game_after_quit(s, game),
+                    Err(error) => s.add_layer(Dialog::info(error)),
+                }
+            }
+        )
+    );
+}
 
-    let generator = if selection == "rip" {
-        Box::new(
-            MyWorldGen::new_param(
-                world_size, 5, 3, 3,
-                true, true, 3, false,
-                None
-            )
-        ) as Box::<dyn Generator>
-    } else {
-        let params = WorldGeneratorParameters {
-            world_size,
-            amount_of_streets: Some(0.7),
-            ..Default::default()
-        };
-        Box::new(
-            WorldGenerator::new(params)
-        ) as Box::<dyn Generator>
+fn open_load_profile(s: &mut Cursive) {
+    let names = match profile::profile_names() {
+        Ok(names) => names,
+        Err(error) => {
+            s.add_layer(Dialog::info(error));
+            return;
+        }
     };
-    
-    (GeneratorWrapper::new(generator), world_size)
+    if names.is_empty() {
+        s.add_layer(Dialog::info(format!("There are no profiles in {}", profile::PROFILES_FILE)));
+        return;
+    }
+
+    let profile_list = SelectView::new()
+        .with_all_str(names)
+        .on_submit(|s, name: &String| {
+            match profile::load_profile(name).and_then(|profile| game::prepare_game(&Registry::default(), &profile)) {
+                Ok(game) => start_game_after_quit(s, game),
+                Err(error) => s.add_layer(Dialog::info(error)),
+            }
+        });
+
+    s.add_layer(
+        Dialog::new()
+        .title("Load profile")
+        .content(profile_list.scrollable().max_height(10))
+        .button("Cancel", |s| { s.pop_layer(); })
+    );
 }
 
-fn start_olympus_visualizer(robot_selection: String, world_generator_selection: String) {
-    let command = "target/release/examples/olympus_demo";
-    let args = [robot_selection, world_generator_selection];
-    Command::new(command)
-        .args(&args)
-        .output()
-        .expect("Error running olympus demo");
+//the game is run by main once cursive gives back the terminal
+fn start_game_after_quit(s: &mut Cursive, game: Game) {
+    s.set_user_data(game);
+    s.quit();
+}
+
+fn open_world_generator_settings(s: &mut Cursive, robot_selection: String, world_generator_selection: String, visualizer_selection: String) {
+    let params = default_parameters();
+
+    let game_layout = ListView::new()
+        .child("Tick speed (ticks/s)", optional_parameter_view("tick_speed", None))
+        .child("Run length (ticks)", LinearLayout::horizontal()
+            .child(Checkbox::new().with_name("run_length_enabled"))
+            .child(DummyView)
+            .child(EditView::new().content("1000").with_name("run_length").fixed_width(10))
+        )
+        .child("Record replay", LinearLayout::horizontal()
+            .child(Checkbox::new().with_name("record_file_enabled"))
+            .child(DummyView)
+            .child(EditView::new().content("game.replay").with_name("record_file").fixed_width(16))
+        );
+
+    let general_layout = ListView::new()
+        .child("Seed", EditView::new().content(params.seed.to_string()).with_name("seed").fixed_width(22))
+        .child("World size", EditView::new().content(params.world_size.to_string()).with_name("world_size").fixed_width(10))
+        .child("Always sunny", Checkbox::new().with_checked(params.always_sunny).with_name("always_sunny"))
+        .child("Rivers", optional_parameter_view("rivers", params.amount_of_rivers))
+        .child("Streets", optional_parameter_view("streets", params.amount_of_streets))
+        .child("Teleports", optional_parameter_view("teleports", params.amount_of_teleports))
+        .child("Elevation multiplier", optional_parameter_view("elevation_multiplier", params.elevation_multiplier))
+        .child("Starting hour", EditView::new().content(params.starting_hour.to_string()).with_name("starting_hour").fixed_width(10))
+        .delimiter();
+
+    let mut contents_radii_list = ListView::new();
+    for field in &CONTENTS_RADII_FIELDS {
+        contents_radii_list.add_child(
+            field.name,
+            EditView::new().content((field.get)(&params.contents_radii).to_string()).with_name(field.name).fixed_width(6)
+        );
+    }
+    let contents_radii_layout = LinearLayout::vertical()
+        .child(TextView::new("Contents radii:"))
+        .child(contents_radii_list.scrollable().max_height(12));
+
+    s.pop_layer();
+    s.add_layer(
+        Dialog::new()
+        .title("World generator settings")
+        .content(
+            LinearLayout::horizontal()
+            .child(
+                LinearLayout::vertical()
+                .child(general_layout)
+                .child(game_layout)
+            )
+            .child(DummyView)
+            .child(contents_radii_layout)
+        )
+        .button("Back", |s| open_game_settings(s))
+        .button("Save profile", {
+                let (robot_selection, world_generator_selection, visualizer_selection) =
+                    (robot_selection.clone(), world_generator_selection.clone(), visualizer_selection.clone());
+                move |s| {
+                    match read_profile(s, &robot_selection, &world_generator_selection, &visualizer_selection) {
+                        Ok(profile) => open_save_profile(s, profile),
+                        Err(error) => s.add_layer(Dialog::info(error)),
+                    }
+                }
+            }
+        )
+        .button("Start", move |s| {
+                let game = read_profile(s, &robot_selection, &world_generator_selection, &visualizer_selection)
+                    .and_then(|profile| game::prepare_game(&Registry::default(), &profile));
+                match game {
+                    Ok(game) => start_game_after_quit(s, game),
+                    Err(error) => s.add_layer(Dialog::info(error)),
+                }
+            }
+        )
+    );
 }
 
 fn main() {
+    let args: Vec<String> = env::args().collect();
+
+    if args.get(1).map(String::as_str) == Some("--headless") {
+        let result = headless::BatchOptions::from_args(&args[2..])
+            .and_then(|options| headless::run_batch(&Registry::default(), &options));
+        //the batch is meant for scripts and CI, so a failure has to show in the exit status
+        if let Err(error) = result {
+            eprintln!("{error}");
+            process::exit(1);
+        }
+        return;
+    }
+    //used internally by the headless mode, runs a single game and prints the result
+    if args.get(1).map(String::as_str) == Some("--headless-game") {
+        if headless::game_process(&Registry::default(), &args[2..]).is_err() {
+            process::exit(1);
+        }
+        return;
+    }
+    if args.get(1).map(String::as_str) == Some("--tournament") {
+        let result = tournament::TournamentOptions::from_args(&args[2..])
+            .and_then(|options| tournament::run_tournament(&Registry::default(), &options));
+        if let Err(error) = result {
+            eprintln!("{error}");
+        }
+        return;
+    }
+    if args.get(1).map(String::as_str) == Some("--benchmark") {
+        let result = benchmark::BenchmarkOptions::from_args(&args[2..])
+            .and_then(|options| benchmark::run_benchmark(&Registry::default(), &options));
+        if let Err(error) = result {
+            eprintln!("{error}");
+        }
+        return;
+    }
+    //used internally by the benchmark, generates a single world and prints its statistics
+    if args.get(1).map(String::as_str) == Some("--benchmark-world") {
+        if benchmark::world_process(&Registry::default(), &args[2..]).is_err() {
+            process::exit(1);
+        }
+        return;
+    }
+    //saves a generated world, "--export-world <generator> <file>" followed by the world generator parameters
+    if args.get(1).map(String::as_str) == Some("--export-world") {
+        let result = match (args.get(2), args.get(3)) {
+            (Some(world_generator_selection), Some(path)) => world_generator_parameters::from_args(&args[4..])
+                .and_then(|params| world_file::build_world_generator(&Registry::default(), world_generator_selection, &params))
+                .and_then(|mut world_generator| world_file::export_world(&mut world_generator, path)),
+            _ => Err("--export-world needs a world generator and a file".to_string()),
+        };
+        match result {
+            Ok(()) => println!("World saved in {}", args[3]),
+            Err(error) => eprintln!("{error}"),
+        }
+        return;
+    }
+    if args.get(1).map(String::as_str) == Some("--replay") {
+        let game = args
+            .get(2)
+            .ok_or("--replay needs a file".to_string())
+            .and_then(|path| game::prepare_replay(path))
+            .and_then(|game| game.run());
+        if let Err(error) = game {
+            eprintln!("{error}");
+        }
+        return;
+    }
+    if args.get(1).map(String::as_str) == Some("--profile") {
+        let game = args
+            .get(2)
+            .ok_or("--profile needs a name".to_string())
+            .and_then(|name| profile::load_profile(name))
+            .and_then(|profile| game::prepare_game(&Registry::default(), &profile))
+            .and_then(|game| game.run());
+        if let Err(error) = game {
+            eprintln!("{error}");
+        }
+        return;
+    }
+
     let mut siv = cursive::default();
 
     siv.add_global_callback('q', |s| s.quit());
 
     siv.add_layer(Dialog::text("What do you want to do?")
         .button("Play game", |s| open_game_settings(s))
+        .button("Load profile", |s| open_load_profile(s))
+        .button("Watch replay", |s| open_watch_replay(s))
         .button("View midgard world generator", move |s| start_midgard_visualizer(s))
         .button("Try swift_seller tool", move |s| start_swift_seller_demo(s))
     );
 
     siv.run();
+
+    if let Some(game) = siv.take_user_data::<Game>() {
+        if let Err(error) = game.run() {
+            eprintln!("{error}");
+        }
+    }
 }
\ No newline at end of file
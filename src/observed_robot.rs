@@ -0,0 +1,43 @@
+use robotics_lib::energy::Energy;
+use robotics_lib::event::events::Event;
+use robotics_lib::runner::backpack::BackPack;
+use robotics_lib::runner::Runnable;
+use robotics_lib::world::coordinates::Coordinate;
+use robotics_lib::world::World;
+
+//called after every tick with the robot, the world and the energy the robot spent during the tick
+type Observer = Box<dyn FnMut(&dyn Runnable, &World, usize)>;
+
+//wraps a robot to look at the world after every tick, the same way ragnarok's RobotWrapper does.
+//The version of robotics_lib the demo depends on has no way to observe the Runner, so the wrapped
+//robot is the only one the World is handed to.
+//The interface sends the events of the robot's actions to the wrapped robot and not to this one,
+//so the energy spent is measured around the tick instead of counted from EnergyConsumed
+pub struct ObservedRobot {
+    robot: Box<dyn Runnable>,
+    observer: Observer,
+}
+
+impl ObservedRobot {
+    pub fn new(robot: Box<dyn Runnable>, observer: impl FnMut(&dyn Runnable, &World, usize) + 'static) -> Self {
+        Self { robot, observer: Box::new(observer) }
+    }
+}
+
+impl Runnable for ObservedRobot {
+    fn process_tick(&mut self, world: &mut World) {
+        let energy = self.robot.get_energy().get_energy_level();
+        self.robot.process_tick(world);
+
+        let energy_consumed = energy.saturating_sub(self.robot.get_energy().get_energy_level());
+        (self.observer)(self.robot.as_ref(), world, energy_consumed);
+    }
+
+    fn handle_event(&mut self, event: Event) { self.robot.handle_event(event) }
+    fn get_energy(&self) -> &Energy { self.robot.get_energy() }
+    fn get_energy_mut(&mut self) -> &mut Energy { self.robot.get_energy_mut() }
+    fn get_coordinate(&self) -> &Coordinate { self.robot.get_coordinate() }
+    fn get_coordinate_mut(&mut self) -> &mut Coordinate { self.robot.get_coordinate_mut() }
+    fn get_backpack(&self) -> &BackPack { self.robot.get_backpack() }
+    fn get_backpack_mut(&mut self) -> &mut BackPack { self.robot.get_backpack_mut() }
+}
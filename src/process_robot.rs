@@ -0,0 +1,312 @@
+use std::env;
+use std::io::{BufRead, BufReader, Write};
+use std::process::{Child, ChildStdin, Command, Stdio};
+use std::sync::mpsc::{self, Receiver, RecvTimeoutError};
+use std::thread;
+use std::time::Duration;
+use robotics_lib::energy::Energy;
+use robotics_lib::event::events::Event;
+use robotics_lib::interface::{craft, destroy, discover_tiles, go, look_at_sky, put, robot_view, teleport, Direction};
+use robotics_lib::runner::backpack::BackPack;
+use robotics_lib::runner::{Robot, Runnable};
+use robotics_lib::utils::LibError;
+use robotics_lib::world::coordinates::Coordinate;
+use robotics_lib::world::environmental_conditions::EnvironmentalConditions;
+use robotics_lib::world::tile::{Content, Tile};
+use robotics_lib::world::World;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use swift_seller::SwiftSeller;
+use crate::replay::RecordedEvent;
+
+//the command run by the "process" robot of the registry, split on whitespace
+pub const COMMAND_VARIABLE: &str = "ROBOT_COMMAND";
+
+//how long the process has to answer an observation before it is stopped
+pub const DEFAULT_TIMEOUT: Duration = Duration::from_secs(1);
+
+//Direction doesn't implement Deserialize, so the actions use this
+#[derive(Deserialize)]
+enum Side {
+    Up,
+    Down,
+    Left,
+    Right,
+}
+
+impl From<Side> for Direction {
+    fn from(side: Side) -> Self {
+        match side {
+            Side::Up => Direction::Up,
+            Side::Down => Direction::Down,
+            Side::Left => Direction::Left,
+            Side::Right => Direction::Right,
+        }
+    }
+}
+
+//an interface call asked by the process, like {"Go":"Up"} or {"Put":[{"Rock":0},2,"Down"]}
+#[derive(Deserialize)]
+enum Action {
+    Go(Side),
+    Destroy(Side),
+    Put(Content, usize, Side),
+    Craft(Content),
+    Teleport(usize, usize),
+    DiscoverTiles(Vec<(usize, usize)>),
+    SwiftSeller(Vec<Content>),
+}
+
+//what an action returned. The maps of the interface become lists, since JSON keys can only be strings
+#[derive(Serialize)]
+enum ActionOutput {
+    //the robot_view and the position after go and teleport
+    Moved(Vec<Vec<Option<Tile>>>, (usize, usize)),
+    Destroyed(usize),
+    Put(usize),
+    Crafted(Content),
+    Discovered(Vec<((usize, usize), Option<Tile>)>),
+    Sold(Vec<(Content, usize)>),
+}
+
+//the LibError is sent like serde would write it: its name, like {"Err":"NotEnoughEnergy"}, or an
+//object for the errors that hold a value, like {"Err":{"NotEnoughSpace":3}}
+type ActionResult = Result<ActionOutput, Value>;
+
+//LibError doesn't implement Serialize
+fn error_json(error: LibError) -> Value {
+    match error {
+        LibError::NotEnoughSpace(added) => json!({ "NotEnoughSpace": added }),
+        error => Value::String(format!("{error:?}")),
+    }
+}
+
+//sent to the process at the start of every tick, as a single line of JSON
+#[derive(Serialize)]
+struct Observation<'a> {
+    tick: usize,
+    view: Vec<Vec<Option<Tile>>>,
+    position: (usize, usize),
+    energy: usize,
+    backpack_size: usize,
+    backpack: Vec<(Content, usize)>,
+    environmental_conditions: EnvironmentalConditions,
+    //received since the previous observation
+    events: &'a [RecordedEvent],
+    //of the actions of the previous tick, in the same order
+    results: &'a [ActionResult],
+}
+
+//a robot played by another process, so it can be written in any language.
+//every tick the process gets an Observation on its stdin and answers with a line holding the list of
+//actions to do, like [{"Go":"Right"},{"Destroy":"Up"}]. Its stderr is left to it for logging.
+//a process that crashes, doesn't answer in time or breaks the protocol is stopped and the robot
+//stays still for the rest of the game
+pub struct ProcessRobot {
+    robot: Robot,
+    command: String,
+    child: Child,
+    stdin: ChildStdin,
+    replies: Receiver<std::io::Result<String>>,
+    timeout: Duration,
+    tick: usize,
+    events: Vec<RecordedEvent>,
+    results: Vec<ActionResult>,
+    stopped: bool,
+}
+
+impl ProcessRobot {
+    pub fn spawn(command: &str, timeout: Duration) -> Result<Self, String> {
+        let mut words = command.split_whitespace();
+        let program = words.next().ok_or("The robot command is empty".to_string())?;
+        let mut child = Command::new(program)
+            .args(words)
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .spawn()
+            .map_err(|error| format!("Error starting {command}: {error}"))?;
+        let stdin = child.stdin.take().ok_or(format!("Error starting {command}: no stdin"))?;
+        let stdout = child.stdout.take().ok_or(format!("Error starting {command}: no stdout"))?;
+
+        //lines are read on their own thread, so waiting for one can time out
+        let (sender, replies) = mpsc::channel();
+        thread::spawn(move || {
+            for line in BufReader::new(stdout).lines() {
+                if sender.send(line).is_err() {
+                    break;
+                }
+            }
+        });
+
+        Ok(Self {
+            robot: Robot::new(),
+            command: command.to_string(),
+            child,
+            stdin,
+            replies,
+            timeout,
+            tick: 0,
+            events: Vec::new(),
+            results: Vec::new(),
+            stopped: false,
+        })
+    }
+
+    //the command in the ROBOT_COMMAND environment variable
+    pub fn from_env() -> Result<Self, String> {
+        let command = env::var(COMMAND_VARIABLE).map_err(|_| format!("Set {COMMAND_VARIABLE} to the command of the robot"))?;
+        Self::spawn(&command, DEFAULT_TIMEOUT)
+    }
+
+    fn observe(&self, world: &World) -> Observation<'_> {
+        let coordinate = &self.robot.coordinate;
+        Observation {
+            tick: self.tick,
+            view: robot_view(self, world),
+            position: (coordinate.get_row(), coordinate.get_col()),
+            energy: self.robot.energy.get_energy_level(),
+            backpack_size: self.robot.backpack.get_size(),
+            backpack: self.robot.backpack
+                .get_contents()
+                .iter()
+                .filter(|(_, amount)| **amount > 0)
+                .map(|(content, amount)| (content.clone(), *amount))
+                .collect(),
+            environmental_conditions: look_at_sky(world),
+            events: &self.events,
+            results: &self.results,
+        }
+    }
+
+    //sends the observation and waits for the actions of this tick
+    fn exchange(&mut self, world: &World) -> Result<Vec<Action>, String> {
+        let mut line = serde_json::to_string(&self.observe(world)).map_err(|error| format!("Error writing the observation: {error}"))?;
+        line.push('\n');
+        self.stdin
+            .write_all(line.as_bytes())
+            .and_then(|_| self.stdin.flush())
+            .map_err(|error| format!("Error writing to the process: {error}"))?;
+
+        match self.replies.recv_timeout(self.timeout) {
+            Ok(Ok(reply)) => serde_json::from_str(&reply).map_err(|error| format!("Invalid actions: {error}")),
+            Ok(Err(error)) => Err(format!("Error reading from the process: {error}")),
+            Err(RecvTimeoutError::Timeout) => Err(format!("No answer in {:?}", self.timeout)),
+            Err(RecvTimeoutError::Disconnected) => Err("The process closed its output".to_string()),
+        }
+    }
+
+    fn stop(&mut self, error: &str) {
+        eprintln!("Robot process {} stopped: {error}", self.command);
+        self.stopped = true;
+        let _ = self.child.kill();
+        let _ = self.child.wait();
+    }
+
+    fn act(&mut self, action: Action, world: &mut World) -> ActionResult {
+        let output = match action {
+            Action::Go(side) => go(self, world, side.into()).map(|(view, position)| ActionOutput::Moved(view, position)),
+            Action::Destroy(side) => destroy(self, world, side.into()).map(ActionOutput::Destroyed),
+            Action::Put(content, quantity, side) => put(self, world, content, quantity, side.into()).map(ActionOutput::Put),
+            Action::Craft(content) => craft(self, content).map(ActionOutput::Crafted),
+            Action::Teleport(row, col) => teleport(self, world, (row, col)).map(|(view, position)| ActionOutput::Moved(view, position)),
+            Action::DiscoverTiles(tiles) => discover_tiles(self, world, &tiles)
+                .map(|tiles| ActionOutput::Discovered(tiles.into_iter().collect())),
+            Action::SwiftSeller(contents) => SwiftSeller::swift_seller(self, world, contents)
+                .map(|sold| ActionOutput::Sold(sold.into_iter().collect())),
+        };
+        output.map_err(error_json)
+    }
+}
+
+impl Runnable for ProcessRobot {
+    fn process_tick(&mut self, world: &mut World) {
+        if self.stopped {
+            return;
+        }
+        self.tick += 1;
+
+        match self.exchange(world) {
+            Ok(actions) => {
+                self.events.clear();
+                self.results = actions.into_iter().map(|action| self.act(action, world)).collect();
+            }
+            Err(error) => self.stop(&error),
+        }
+    }
+
+    fn handle_event(&mut self, event: Event) {
+        if !self.stopped {
+            self.events.push(RecordedEvent::from(&event));
+        }
+    }
+    fn get_energy(&self) -> &Energy { &self.robot.energy }
+    fn get_energy_mut(&mut self) -> &mut Energy { &mut self.robot.energy }
+    fn get_coordinate(&self) -> &Coordinate { &self.robot.coordinate }
+    fn get_coordinate_mut(&mut self) -> &mut Coordinate { &mut self.robot.coordinate }
+    fn get_backpack(&self) -> &BackPack { &self.robot.backpack }
+    fn get_backpack_mut(&mut self) -> &mut BackPack { &mut self.robot.backpack }
+}
+
+//the process is stopped with the game
+impl Drop for ProcessRobot {
+    fn drop(&mut self) {
+        let _ = self.child.kill();
+        let _ = self.child.wait();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+    use std::time::Instant;
+    use robotics_lib::runner::Runner;
+    use crate::test_world::TestWorld;
+    use super::*;
+
+    //plays a shell script as the robot and returns where the robot ended up
+    fn play(name: &str, script: &str, timeout: Duration, ticks: usize) -> (usize, usize) {
+        let path = env::temp_dir().join(format!("process_robot_{name}_{}.sh", std::process::id()));
+        fs::write(&path, script).unwrap();
+        let robot = ProcessRobot::spawn(&format!("sh {}", path.display()), timeout).unwrap();
+        let mut runner = Runner::new(Box::new(robot), &mut TestWorld).unwrap();
+        for _ in 0..ticks {
+            runner.game_tick().unwrap();
+        }
+        let coordinate = runner.get_robot().get_coordinate();
+        let position = (coordinate.get_row(), coordinate.get_col());
+        fs::remove_file(&path).unwrap();
+        position
+    }
+
+    #[test]
+    fn actions_are_played() {
+        let script = "while read line; do echo '[{\"Go\":\"Down\"}]'; done\n";
+        assert_eq!(play("actions", script, DEFAULT_TIMEOUT, 2), (2, 0));
+    }
+
+    #[test]
+    fn malformed_reply_stops_the_robot() {
+        let script = "read line\necho 'go down'\nwhile read line; do echo '[{\"Go\":\"Down\"}]'; done\n";
+        assert_eq!(play("malformed", script, DEFAULT_TIMEOUT, 3), (0, 0));
+    }
+
+    #[test]
+    fn crash_stops_the_robot() {
+        let script = "read line\necho '[{\"Go\":\"Down\"}]'\nexit 1\n";
+        assert_eq!(play("crash", script, DEFAULT_TIMEOUT, 3), (1, 0));
+    }
+
+    #[test]
+    fn timeout_stops_the_robot() {
+        let script = "read line\nsleep 2\nwhile read line; do echo '[{\"Go\":\"Down\"}]'; done\n";
+        let start = Instant::now();
+        assert_eq!(play("timeout", script, Duration::from_millis(200), 3), (0, 0));
+        assert!(start.elapsed() < Duration::from_secs(2));
+    }
+
+    #[test]
+    fn errors_are_sent_like_serde_writes_them() {
+        assert_eq!(error_json(LibError::NotEnoughEnergy).to_string(), "\"NotEnoughEnergy\"");
+        assert_eq!(error_json(LibError::NotEnoughSpace(3)).to_string(), "{\"NotEnoughSpace\":3}");
+    }
+}
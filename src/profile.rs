@@ -0,0 +1,165 @@
+use std::collections::BTreeMap;
+use std::fs;
+use std::io::ErrorKind;
+use midgard::params::WorldGeneratorParameters;
+use serde::{Deserialize, Serialize};
+use crate::world_generator_parameters::{default_parameters, validate, CONTENTS_RADII_FIELDS};
+
+pub const PROFILES_FILE: &str = "profiles.toml";
+
+//everything needed to start a game without going through the dialogs
+#[derive(Clone)]
+pub struct Profile {
+    pub robot: String,
+    pub world_generator: String,
+    pub visualizer: String,
+    pub world_generator_parameters: WorldGeneratorParameters,
+    //maximum ticks per second, None means as fast as the visualizer allows
+    pub tick_speed: Option<f32>,
+    //number of ticks the robot is allowed to play, None means forever
+    pub run_length: Option<usize>,
+    //replay file written while the game runs
+    pub record_file: Option<String>,
+}
+
+//WorldGeneratorParameters doesn't implement Serialize, so the profiles are stored through these
+#[derive(Serialize, Deserialize)]
+struct ProfileEntry {
+    robot: String,
+    world_generator: String,
+    visualizer: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    tick_speed: Option<f32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    run_length: Option<usize>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    record_file: Option<String>,
+    world_generator_parameters: ParametersEntry,
+}
+
+//disabled optional parameters are left out of the file
+#[derive(Serialize, Deserialize)]
+struct ParametersEntry {
+    //toml integers are signed, a random u64 seed doesn't always fit
+    seed: String,
+    world_size: usize,
+    always_sunny: bool,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    rivers: Option<f64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    streets: Option<f64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    teleports: Option<f64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    elevation_multiplier: Option<f64>,
+    starting_hour: u8,
+    #[serde(default)]
+    contents_radii: BTreeMap<String, u64>,
+}
+
+#[derive(Serialize, Deserialize, Default)]
+struct ProfilesFile {
+    #[serde(default)]
+    profiles: BTreeMap<String, ProfileEntry>,
+}
+
+impl From<&Profile> for ProfileEntry {
+    fn from(profile: &Profile) -> Self {
+        let params = &profile.world_generator_parameters;
+
+        Self {
+            robot: profile.robot.clone(),
+            world_generator: profile.world_generator.clone(),
+            visualizer: profile.visualizer.clone(),
+            tick_speed: profile.tick_speed,
+            run_length: profile.run_length,
+            record_file: profile.record_file.clone(),
+            world_generator_parameters: ParametersEntry {
+                seed: params.seed.to_string(),
+                world_size: params.world_size,
+                always_sunny: params.always_sunny,
+                rivers: params.amount_of_rivers,
+                streets: params.amount_of_streets,
+                teleports: params.amount_of_teleports,
+                elevation_multiplier: params.elevation_multiplier,
+                starting_hour: params.starting_hour,
+                contents_radii: CONTENTS_RADII_FIELDS
+                    .iter()
+                    .map(|field| (field.name.to_string(), (field.get)(&params.contents_radii)))
+                    .collect(),
+            },
+        }
+    }
+}
+
+impl TryFrom<ProfileEntry> for Profile {
+    type Error = String;
+
+    fn try_from(entry: ProfileEntry) -> Result<Self, Self::Error> {
+        let entry_params = entry.world_generator_parameters;
+        let mut params = default_parameters();
+
+        params.seed = entry_params.seed.parse().map_err(|_| format!("invalid seed: {}", entry_params.seed))?;
+        params.world_size = entry_params.world_size;
+        params.always_sunny = entry_params.always_sunny;
+        params.amount_of_rivers = entry_params.rivers;
+        params.amount_of_streets = entry_params.streets;
+        params.amount_of_teleports = entry_params.teleports;
+        params.elevation_multiplier = entry_params.elevation_multiplier;
+        params.starting_hour = entry_params.starting_hour;
+        //missing radii keep their default value
+        for (name, radius) in entry_params.contents_radii {
+            let field = CONTENTS_RADII_FIELDS
+                .iter()
+                .find(|field| field.name == name)
+                .ok_or(format!("unknown contents radius: {name}"))?;
+            (field.set)(&mut params.contents_radii, radius);
+        }
+        validate(&params)?;
+
+        if entry.tick_speed.is_some_and(|tick_speed| !tick_speed.is_finite() || tick_speed <= 0.0) {
+            return Err("tick_speed must be greater than 0".to_string());
+        }
+
+        Ok(Self {
+            robot: entry.robot,
+            world_generator: entry.world_generator,
+            visualizer: entry.visualizer,
+            world_generator_parameters: params,
+            tick_speed: entry.tick_speed,
+            run_length: entry.run_length,
+            record_file: entry.record_file,
+        })
+    }
+}
+
+fn read_profiles_file() -> Result<ProfilesFile, String> {
+    match fs::read_to_string(PROFILES_FILE) {
+        Ok(text) => toml::from_str(&text).map_err(|error| format!("Error reading {PROFILES_FILE}: {error}")),
+        //no profiles have been saved yet
+        Err(error) if error.kind() == ErrorKind::NotFound => Ok(ProfilesFile::default()),
+        Err(error) => Err(format!("Error reading {PROFILES_FILE}: {error}")),
+    }
+}
+
+pub fn profile_names() -> Result<Vec<String>, String> {
+    Ok(read_profiles_file()?.profiles.into_keys().collect())
+}
+
+pub fn load_profile(name: &str) -> Result<Profile, String> {
+    let entry = read_profiles_file()?
+        .profiles
+        .remove(name)
+        .ok_or(format!("There is no profile named \"{name}\" in {PROFILES_FILE}"))?;
+
+    Profile::try_from(entry).map_err(|error| format!("Profile \"{name}\" is not valid: {error}"))
+}
+
+//overwrites the profile with the same name, if there is one
+pub fn save_profile(name: &str, profile: &Profile) -> Result<(), String> {
+    let mut profiles_file = read_profiles_file()?;
+    profiles_file.profiles.insert(name.to_string(), ProfileEntry::from(profile));
+
+    let text = toml::to_string_pretty(&profiles_file).map_err(|error| format!("Error saving the profile: {error}"))?;
+    fs::write(PROFILES_FILE, text).map_err(|error| format!("Error writing {PROFILES_FILE}: {error}"))
+}
@@ -0,0 +1,149 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+use ai::MyRobot;
+use bmo::BMO;
+use midgard::params::WorldGeneratorParameters;
+use midgard::WorldGenerator;
+use olympus::channel::Channel;
+use rip_worldgenerator::MyWorldGen;
+use robotics_lib::runner::Runnable;
+use robotics_lib::world::world_generator::Generator;
+use crate::process_robot::ProcessRobot;
+use crate::script_robot::{ScriptRobot, SCRIPT_FILE};
+
+type RobotFactory = Box<dyn Fn(Rc<RefCell<Channel>>) -> Result<Box<dyn Runnable>, String>>;
+type WorldGeneratorFactory = Box<dyn Fn(&WorldGeneratorParameters) -> Box<dyn Generator>>;
+
+pub struct RobotEntry {
+    pub name: &'static str,
+    pub description: &'static str,
+    //the robot reads a file or an environment variable the user has to set up, so it can't simply
+    //be played on its own like in a tournament
+    pub needs_setup: bool,
+    factory: RobotFactory,
+}
+
+impl RobotEntry {
+    //robots need to have a channel for compatibility with olympus
+    pub fn build(&self, channel: Rc<RefCell<Channel>>) -> Result<Box<dyn Runnable>, String> {
+        (self.factory)(channel)
+    }
+}
+
+pub struct WorldGeneratorEntry {
+    pub name: &'static str,
+    pub description: &'static str,
+    factory: WorldGeneratorFactory,
+}
+
+impl WorldGeneratorEntry {
+    //generators other than midgard only use the parameters they support, like world_size and seed
+    pub fn build(&self, params: &WorldGeneratorParameters) -> GeneratorWrapper {
+        GeneratorWrapper::new((self.factory)(params))
+    }
+}
+
+//used for getting a impl Generator from a dyn Generator
+pub struct GeneratorWrapper {
+    generator: Box<dyn Generator>
+}
+impl Generator for GeneratorWrapper {
+    fn gen(&mut self) -> robotics_lib::world::world_generator::World { self.generator.gen() }
+}
+impl GeneratorWrapper {
+    pub fn new(generator: Box<dyn Generator>) -> Self { Self { generator } }
+}
+
+//every robot and world generator the demo knows about, the launcher and the
+//headless mode all build their options from here
+pub struct Registry {
+    robots: Vec<RobotEntry>,
+    world_generators: Vec<WorldGeneratorEntry>,
+}
+
+impl Registry {
+    pub fn empty() -> Self {
+        Self {
+            robots: vec![],
+            world_generators: vec![],
+        }
+    }
+
+    pub fn register_robot(
+        &mut self,
+        name: &'static str,
+        description: &'static str,
+        factory: impl Fn(Rc<RefCell<Channel>>) -> Result<Box<dyn Runnable>, String> + 'static
+    ) {
+        self.robots.push(RobotEntry { name, description, needs_setup: false, factory: Box::new(factory) });
+    }
+
+    pub fn register_robot_with_setup(
+        &mut self,
+        name: &'static str,
+        description: &'static str,
+        factory: impl Fn(Rc<RefCell<Channel>>) -> Result<Box<dyn Runnable>, String> + 'static
+    ) {
+        self.robots.push(RobotEntry { name, description, needs_setup: true, factory: Box::new(factory) });
+    }
+
+    pub fn register_world_generator(
+        &mut self,
+        name: &'static str,
+        description: &'static str,
+        factory: impl Fn(&WorldGeneratorParameters) -> Box<dyn Generator> + 'static
+    ) {
+        self.world_generators.push(WorldGeneratorEntry { name, description, factory: Box::new(factory) });
+    }
+
+    pub fn robots(&self) -> &[RobotEntry] {
+        &self.robots
+    }
+
+    pub fn world_generators(&self) -> &[WorldGeneratorEntry] {
+        &self.world_generators
+    }
+
+    pub fn robot(&self, name: &str) -> Option<&RobotEntry> {
+        self.robots.iter().find(|entry| entry.name == name)
+    }
+
+    pub fn world_generator(&self, name: &str) -> Option<&WorldGeneratorEntry> {
+        self.world_generators.iter().find(|entry| entry.name == name)
+    }
+}
+
+impl Default for Registry {
+    fn default() -> Self {
+        let mut registry = Self::empty();
+
+        registry.register_robot("ai", "ai: explores using sense_and_find", |channel| {
+            Ok(Box::new(MyRobot::new(channel)))
+        });
+        registry.register_robot("bmo", "bmo: walks randomly", |channel| {
+            Ok(Box::new(BMO::new(channel)))
+        });
+        //the script can be missing or invalid, the error is shown when the game starts
+        registry.register_robot_with_setup("script", "script: runs robot.script", |_channel| {
+            Ok(Box::new(ScriptRobot::load(SCRIPT_FILE)?))
+        });
+        registry.register_robot_with_setup("process", "process: runs the program in $ROBOT_COMMAND", |_channel| {
+            Ok(Box::new(ProcessRobot::from_env()?))
+        });
+
+        registry.register_world_generator("rip", "rip_worldgenerator", |params| {
+            Box::new(
+                MyWorldGen::new_param(
+                    params.world_size, 5, 3, 3,
+                    true, true, 3, false,
+                    Some(params.seed)
+                )
+            )
+        });
+        registry.register_world_generator("midgard", "midgard: biomes, rivers and streets", |params| {
+            Box::new(WorldGenerator::new(params.clone()))
+        });
+
+        registry
+    }
+}
@@ -0,0 +1,281 @@
+use std::collections::HashMap;
+use std::fs::File;
+use std::io::{BufReader, BufWriter, ErrorKind, Write};
+use robotics_lib::energy::Energy;
+use robotics_lib::event::events::Event;
+use robotics_lib::interface::{get_score, look_at_sky, robot_map};
+use robotics_lib::runner::backpack::BackPack;
+use robotics_lib::runner::Runnable;
+use robotics_lib::world::coordinates::Coordinate;
+use robotics_lib::world::environmental_conditions::EnvironmentalConditions;
+use robotics_lib::world::tile::{Content, Tile};
+use robotics_lib::world::World;
+use serde::{Deserialize, Serialize};
+
+//bumped whenever the layout of the records changes, old files are refused instead of misread
+const REPLAY_VERSION: u32 = 1;
+
+//Event doesn't implement Serialize, so it is stored through this
+#[derive(Serialize, Deserialize, Clone)]
+pub enum RecordedEvent {
+    Ready,
+    Terminated,
+    TimeChanged(EnvironmentalConditions),
+    DayChanged(EnvironmentalConditions),
+    EnergyRecharged(usize),
+    EnergyConsumed(usize),
+    Moved(Tile, (usize, usize)),
+    TileContentUpdated(Tile, (usize, usize)),
+    AddedToBackpack(Content, usize),
+    RemovedFromBackpack(Content, usize),
+}
+
+impl From<&Event> for RecordedEvent {
+    fn from(event: &Event) -> Self {
+        match event {
+            Event::Ready => Self::Ready,
+            Event::Terminated => Self::Terminated,
+            Event::TimeChanged(environmental_conditions) => Self::TimeChanged(environmental_conditions.clone()),
+            Event::DayChanged(environmental_conditions) => Self::DayChanged(environmental_conditions.clone()),
+            Event::EnergyRecharged(amount) => Self::EnergyRecharged(*amount),
+            Event::EnergyConsumed(amount) => Self::EnergyConsumed(*amount),
+            Event::Moved(tile, position) => Self::Moved(tile.clone(), *position),
+            Event::TileContentUpdated(tile, position) => Self::TileContentUpdated(tile.clone(), *position),
+            Event::AddedToBackpack(content, amount) => Self::AddedToBackpack(content.clone(), *amount),
+            Event::RemovedFromBackpack(content, amount) => Self::RemovedFromBackpack(content.clone(), *amount),
+        }
+    }
+}
+
+impl From<&RecordedEvent> for Event {
+    fn from(event: &RecordedEvent) -> Self {
+        match event {
+            RecordedEvent::Ready => Event::Ready,
+            RecordedEvent::Terminated => Event::Terminated,
+            RecordedEvent::TimeChanged(environmental_conditions) => Event::TimeChanged(environmental_conditions.clone()),
+            RecordedEvent::DayChanged(environmental_conditions) => Event::DayChanged(environmental_conditions.clone()),
+            RecordedEvent::EnergyRecharged(amount) => Event::EnergyRecharged(*amount),
+            RecordedEvent::EnergyConsumed(amount) => Event::EnergyConsumed(*amount),
+            RecordedEvent::Moved(tile, position) => Event::Moved(tile.clone(), *position),
+            RecordedEvent::TileContentUpdated(tile, position) => Event::TileContentUpdated(tile.clone(), *position),
+            RecordedEvent::AddedToBackpack(content, amount) => Event::AddedToBackpack(content.clone(), *amount),
+            RecordedEvent::RemovedFromBackpack(content, amount) => Event::RemovedFromBackpack(content.clone(), *amount),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+struct ReplayHeader {
+    version: u32,
+    world_size: usize,
+}
+
+//the state of the game at the end of a tick. The interface sends the events of the robot's actions
+//to the recorded robot and not to the RecordingRobot around it, so what the robot did is recorded
+//as the state it left: the tiles, the position, the energy and the backpack. The only events are
+//the ones the Runner sent since the previous record, so the weather and recharge events of a tick
+//show up at the start of the next one
+#[derive(Serialize, Deserialize, Clone)]
+pub struct TickRecord {
+    pub runner_events: Vec<RecordedEvent>,
+    //only the tiles of the robot_map that changed during the tick
+    pub map_diff: Vec<((usize, usize), Tile)>,
+    pub robot_position: (usize, usize),
+    pub energy: usize,
+    //measured around the robot's tick, the Runner's recharges don't add up once the energy is full
+    pub energy_consumed: usize,
+    pub backpack_size: usize,
+    pub backpack: Vec<(Content, usize)>,
+    pub score: f32,
+    pub environmental_conditions: EnvironmentalConditions,
+}
+
+//wraps a robot and writes a replay file while it plays. Every record is flushed as soon as the tick
+//ends, so a game that panics still leaves a file with everything up to the crash
+pub struct RecordingRobot {
+    robot: Box<dyn Runnable>,
+    writer: BufWriter<File>,
+    known_map: Option<Vec<Vec<Option<Tile>>>>,
+    pending_events: Vec<RecordedEvent>,
+}
+
+impl RecordingRobot {
+    pub fn new(robot: Box<dyn Runnable>, path: &str) -> Result<Self, String> {
+        let file = File::create(path).map_err(|error| format!("Error creating {path}: {error}"))?;
+
+        Ok(Self {
+            robot,
+            writer: BufWriter::new(file),
+            known_map: None,
+            pending_events: Vec::new(),
+        })
+    }
+
+    fn record_tick(&mut self, world: &World, energy_consumed: usize) -> bincode::Result<()> {
+        let Some(new_map) = robot_map(world) else {
+            return Ok(());
+        };
+
+        //the world size is only known once the robot_map can be read
+        let old_map = match self.known_map.take() {
+            Some(old_map) => old_map,
+            None => {
+                let header = ReplayHeader { version: REPLAY_VERSION, world_size: new_map.len() };
+                bincode::serialize_into(&mut self.writer, &header)?;
+                vec![vec![None; new_map.len()]; new_map.len()]
+            }
+        };
+
+        let mut map_diff = Vec::new();
+        for (row, (old_row, new_row)) in old_map.iter().zip(&new_map).enumerate() {
+            for (col, (old_tile, new_tile)) in old_row.iter().zip(new_row).enumerate() {
+                //discovered tiles never go back to None
+                if let Some(tile) = new_tile.as_ref().filter(|tile| old_tile.as_ref() != Some(*tile)) {
+                    map_diff.push(((row, col), tile.clone()));
+                }
+            }
+        }
+
+        let coordinate = self.robot.get_coordinate();
+        let record = TickRecord {
+            runner_events: std::mem::take(&mut self.pending_events),
+            map_diff,
+            robot_position: (coordinate.get_row(), coordinate.get_col()),
+            energy: self.robot.get_energy().get_energy_level(),
+            energy_consumed,
+            backpack_size: self.robot.get_backpack().get_size(),
+            backpack: self.robot.get_backpack().get_contents().iter().map(|(content, amount)| (content.clone(), *amount)).collect(),
+            score: get_score(world),
+            environmental_conditions: look_at_sky(world),
+        };
+        bincode::serialize_into(&mut self.writer, &record)?;
+        self.writer.flush()?;
+
+        self.known_map = Some(new_map);
+        Ok(())
+    }
+}
+
+impl Runnable for RecordingRobot {
+    fn process_tick(&mut self, world: &mut World) {
+        let energy = self.robot.get_energy().get_energy_level();
+        self.robot.process_tick(world);
+
+        let energy_consumed = energy.saturating_sub(self.robot.get_energy().get_energy_level());
+        if let Err(error) = self.record_tick(world, energy_consumed) {
+            eprintln!("Error writing the replay: {error}");
+        }
+    }
+
+    fn handle_event(&mut self, event: Event) {
+        self.pending_events.push(RecordedEvent::from(&event));
+        self.robot.handle_event(event)
+    }
+    fn get_energy(&self) -> &Energy { self.robot.get_energy() }
+    fn get_energy_mut(&mut self) -> &mut Energy { self.robot.get_energy_mut() }
+    fn get_coordinate(&self) -> &Coordinate { self.robot.get_coordinate() }
+    fn get_coordinate_mut(&mut self) -> &mut Coordinate { self.robot.get_coordinate_mut() }
+    fn get_backpack(&self) -> &BackPack { self.robot.get_backpack() }
+    fn get_backpack_mut(&mut self) -> &mut BackPack { self.robot.get_backpack_mut() }
+}
+
+//a replay file loaded in memory
+pub struct Replay {
+    pub world_size: usize,
+    pub ticks: Vec<TickRecord>,
+    //the robot_map after the first `applied` records, so playing forward only adds the new tiles
+    robot_map: Vec<Vec<Option<Tile>>>,
+    applied: usize,
+}
+
+impl Replay {
+    pub fn load(path: &str) -> Result<Self, String> {
+        let file = File::open(path).map_err(|error| format!("Error opening {path}: {error}"))?;
+        let mut reader = BufReader::new(file);
+        let invalid = |error: bincode::Error| format!("{path} is not a valid replay: {error}");
+
+        let header: ReplayHeader = bincode::deserialize_from(&mut reader).map_err(invalid)?;
+        if header.version != REPLAY_VERSION {
+            return Err(format!("{path} was recorded with replay version {}, expected {REPLAY_VERSION}", header.version));
+        }
+
+        let mut ticks = Vec::new();
+        loop {
+            match bincode::deserialize_from(&mut reader) {
+                Ok(record) => ticks.push(record),
+                //the records have no count, the file simply ends after the last one
+                Err(error) if matches!(&*error, bincode::ErrorKind::Io(io_error) if io_error.kind() == ErrorKind::UnexpectedEof) => break,
+                Err(error) => return Err(invalid(error)),
+            }
+        }
+
+        Ok(Self {
+            world_size: header.world_size,
+            ticks,
+            robot_map: vec![vec![None; header.world_size]; header.world_size],
+            applied: 0,
+        })
+    }
+
+    //brings the robot_map to the end of the tick. Going back rebuilds it from the first tick, since
+    //the records only hold the tiles that changed
+    fn apply(&mut self, tick: usize) {
+        if tick + 1 < self.applied {
+            self.robot_map = vec![vec![None; self.world_size]; self.world_size];
+            self.applied = 0;
+        }
+        for record in &self.ticks[self.applied..=tick] {
+            for ((row, col), tile) in &record.map_diff {
+                self.robot_map[*row][*col] = Some(tile.clone());
+            }
+        }
+        self.applied = tick + 1;
+    }
+
+    //the robot_map as it was at the end of the tick
+    pub fn robot_map_at(&mut self, tick: usize) -> Vec<Vec<Option<Tile>>> {
+        self.apply(tick);
+        self.robot_map.clone()
+    }
+
+    pub fn backpack_at(&self, tick: usize) -> HashMap<Content, usize> {
+        self.ticks[tick].backpack.iter().cloned().collect()
+    }
+
+    //the events of the Runner followed by the ones of the robot's actions, worked out from how its
+    //state changed since the previous tick. The position before the first tick isn't recorded, so
+    //the first tick has no Moved
+    pub fn events_at(&mut self, tick: usize) -> Vec<Event> {
+        self.apply(tick);
+        let record = &self.ticks[tick];
+        let previous = tick.checked_sub(1).map(|previous| &self.ticks[previous]);
+        let mut events = record.runner_events.iter().map(Event::from).collect::<Vec<_>>();
+
+        let (row, col) = record.robot_position;
+        let moved = previous.is_some_and(|previous| previous.robot_position != record.robot_position);
+        if let Some(tile) = self.robot_map[row][col].as_ref().filter(|_| moved) {
+            events.push(Event::Moved(tile.clone(), record.robot_position));
+        }
+        if record.energy_consumed > 0 {
+            events.push(Event::EnergyConsumed(record.energy_consumed));
+        }
+
+        let before: HashMap<Content, usize> = previous.map(|previous| previous.backpack.iter().cloned().collect()).unwrap_or_default();
+        let after: HashMap<Content, usize> = record.backpack.iter().cloned().collect();
+        let mut contents = before.keys().chain(after.keys()).cloned().collect::<Vec<_>>();
+        //the backpack is a HashMap, sorted so the events come out in the same order every time
+        contents.sort_by_key(|content| content.to_string());
+        contents.dedup();
+        for content in contents {
+            let old_amount = before.get(&content).copied().unwrap_or(0);
+            let amount = after.get(&content).copied().unwrap_or(0);
+            if amount > old_amount {
+                events.push(Event::AddedToBackpack(content, amount - old_amount));
+            } else if amount < old_amount {
+                events.push(Event::RemovedFromBackpack(content, old_amount - amount));
+            }
+        }
+
+        events
+    }
+}
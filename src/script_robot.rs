@@ -0,0 +1,465 @@
+use std::fs;
+use std::rc::Rc;
+use robotics_lib::energy::Energy;
+use robotics_lib::event::events::Event;
+use robotics_lib::interface::{craft, destroy, discover_tiles, go, look_at_sky, put, robot_view, teleport, Direction};
+use robotics_lib::runner::backpack::BackPack;
+use robotics_lib::runner::{Robot, Runnable};
+use robotics_lib::world::coordinates::Coordinate;
+use robotics_lib::world::environmental_conditions::WeatherType;
+use robotics_lib::world::tile::Content;
+use robotics_lib::world::World;
+use strum::IntoEnumIterator;
+use swift_seller::SwiftSeller;
+
+//the script run by the "script" robot of the registry, read from the working directory
+pub const SCRIPT_FILE: &str = "robot.script";
+
+//statements run in a single tick without reaching an action, past this the tick ends so that a
+//loop without actions can't freeze the game
+const MAX_STEPS_PER_TICK: usize = 1000;
+
+#[derive(Clone, Copy)]
+enum Comparison {
+    Less,
+    LessOrEqual,
+    Greater,
+    GreaterOrEqual,
+    Equal,
+    NotEqual,
+}
+
+impl Comparison {
+    fn compare(self, left: usize, right: usize) -> bool {
+        match self {
+            Comparison::Less => left < right,
+            Comparison::LessOrEqual => left <= right,
+            Comparison::Greater => left > right,
+            Comparison::GreaterOrEqual => left >= right,
+            Comparison::Equal => left == right,
+            Comparison::NotEqual => left != right,
+        }
+    }
+}
+
+enum Condition {
+    Energy(Comparison, usize),
+    Backpack(Content, Comparison, usize),
+    //the content of the tile next to the robot, false outside of the map
+    ContentIs(Direction, Content),
+    WeatherIs(WeatherType),
+    Not(Box<Condition>),
+}
+
+#[derive(Clone, Debug)]
+enum Action {
+    Go(Direction),
+    Destroy(Direction),
+    Put(Content, usize, Direction),
+    Craft(Content),
+    Teleport(usize, usize),
+    Discover(Vec<(usize, usize)>),
+    Sell(Vec<Content>),
+    Wait,
+}
+
+enum Statement {
+    Action(Action),
+    //None repeats forever
+    Repeat(Option<usize>, Rc<Vec<Statement>>),
+    While(Rc<Condition>, Rc<Vec<Statement>>),
+    If(Condition, Rc<Vec<Statement>>, Rc<Vec<Statement>>),
+}
+
+//what happens when the robot reaches the end of a block
+enum Loop {
+    Once,
+    Forever,
+    //the runs left, the current one included
+    Times(usize),
+    While(Rc<Condition>),
+}
+
+//a block being run, the script keeps a stack of them to pick up where it stopped in the next tick
+struct Frame {
+    block: Rc<Vec<Statement>>,
+    next: usize,
+    repeat: Loop,
+}
+
+impl Frame {
+    fn new(block: &Rc<Vec<Statement>>, repeat: Loop) -> Self {
+        Self { block: Rc::clone(block), next: 0, repeat }
+    }
+}
+
+//a robot that runs a plain text script, doing one action per tick:
+//
+//  # comments start with a hash
+//  repeat 3            repeats the block 3 times, "repeat" alone repeats it forever
+//    go right          go, destroy: up, down, left or right
+//  end
+//  while energy > 100  energy, backpack <content>: compared with <, <=, >, >=, == or !=
+//    if up is rock     <direction> is <content>, weather is <weather>, "not" negates a condition
+//      destroy up
+//    else
+//      go up
+//    end
+//  end
+//  put rock 2 down     put <content> <quantity> <direction>
+//  craft jollyblock
+//  teleport 3 5        teleport <row> <col>
+//  discover 0 0 0 1    discover <row> <col>, any number of tiles
+//  sell rock tree      sells the contents with swift_seller
+//  wait                does nothing for a tick
+//
+//contents and weathers are named like their variants, ignoring the case. Failed actions are
+//ignored, the script goes on with the next one. Once the script is over the robot stays still
+pub struct ScriptRobot {
+    robot: Robot,
+    frames: Vec<Frame>,
+}
+
+impl ScriptRobot {
+    pub fn new(script: &str) -> Result<Self, String> {
+        let script = Rc::new(parse_script(script)?);
+        Ok(Self {
+            robot: Robot::new(),
+            frames: vec![Frame::new(&script, Loop::Once)],
+        })
+    }
+
+    pub fn load(path: &str) -> Result<Self, String> {
+        let script = fs::read_to_string(path).map_err(|error| format!("Error reading {path}: {error}"))?;
+        Self::new(&script).map_err(|error| format!("Error in {path}: {error}"))
+    }
+
+    fn check(&self, condition: &Condition, world: &World) -> bool {
+        match condition {
+            Condition::Energy(comparison, value) => comparison.compare(self.robot.energy.get_energy_level(), *value),
+            Condition::Backpack(content, comparison, value) => {
+                let amount = self.robot.backpack.get_contents().get(content).copied().unwrap_or(0);
+                comparison.compare(amount, *value)
+            }
+            Condition::ContentIs(direction, content) => {
+                let (row, col) = match direction {
+                    Direction::Up => (0, 1),
+                    Direction::Down => (2, 1),
+                    Direction::Left => (1, 0),
+                    Direction::Right => (1, 2),
+                };
+                robot_view(self, world)[row][col]
+                    .as_ref()
+                    .is_some_and(|tile| tile.content.to_default() == *content)
+            }
+            Condition::WeatherIs(weather) => look_at_sky(world).get_weather_condition() == *weather,
+            Condition::Not(condition) => !self.check(condition, world),
+        }
+    }
+
+    //whether the block on top of the stack runs again now that it's over
+    fn loop_again(&mut self, world: &World) -> bool {
+        let Some(frame) = self.frames.last_mut() else {
+            return false;
+        };
+        match &mut frame.repeat {
+            Loop::Once => false,
+            Loop::Forever => true,
+            Loop::Times(runs) => {
+                *runs -= 1;
+                *runs > 0
+            }
+            Loop::While(condition) => {
+                let condition = Rc::clone(condition);
+                self.check(&condition, world)
+            }
+        }
+    }
+
+    //walks the script up to its next action, None once the script is over or after too many steps
+    fn next_action(&mut self, world: &World) -> Option<Action> {
+        for _ in 0..MAX_STEPS_PER_TICK {
+            let frame = self.frames.last_mut()?;
+            let block = Rc::clone(&frame.block);
+            let Some(statement) = block.get(frame.next) else {
+                if self.loop_again(world) {
+                    self.frames.last_mut()?.next = 0;
+                } else {
+                    self.frames.pop();
+                }
+                continue;
+            };
+            frame.next += 1;
+
+            match statement {
+                Statement::Action(action) => return Some(action.clone()),
+                Statement::Repeat(None, body) => self.frames.push(Frame::new(body, Loop::Forever)),
+                Statement::Repeat(Some(0), _) => {}
+                Statement::Repeat(Some(times), body) => self.frames.push(Frame::new(body, Loop::Times(*times))),
+                Statement::While(condition, body) => {
+                    if self.check(condition, world) {
+                        self.frames.push(Frame::new(body, Loop::While(Rc::clone(condition))));
+                    }
+                }
+                Statement::If(condition, then, otherwise) => {
+                    let block = if self.check(condition, world) { then } else { otherwise };
+                    self.frames.push(Frame::new(block, Loop::Once));
+                }
+            }
+        }
+        None
+    }
+
+    fn act(&mut self, action: Action, world: &mut World) {
+        match action {
+            Action::Go(direction) => { let _ = go(self, world, direction); }
+            Action::Destroy(direction) => { let _ = destroy(self, world, direction); }
+            Action::Put(content, quantity, direction) => { let _ = put(self, world, content, quantity, direction); }
+            Action::Craft(content) => { let _ = craft(self, content); }
+            Action::Teleport(row, col) => { let _ = teleport(self, world, (row, col)); }
+            Action::Discover(tiles) => { let _ = discover_tiles(self, world, &tiles); }
+            Action::Sell(contents) => { let _ = SwiftSeller::swift_seller(self, world, contents); }
+            Action::Wait => {}
+        }
+    }
+}
+
+impl Runnable for ScriptRobot {
+    fn process_tick(&mut self, world: &mut World) {
+        if let Some(action) = self.next_action(world) {
+            self.act(action, world);
+        }
+    }
+
+    fn handle_event(&mut self, _event: Event) {}
+    fn get_energy(&self) -> &Energy { &self.robot.energy }
+    fn get_energy_mut(&mut self) -> &mut Energy { &mut self.robot.energy }
+    fn get_coordinate(&self) -> &Coordinate { &self.robot.coordinate }
+    fn get_coordinate_mut(&mut self) -> &mut Coordinate { &mut self.robot.coordinate }
+    fn get_backpack(&self) -> &BackPack { &self.robot.backpack }
+    fn get_backpack_mut(&mut self) -> &mut BackPack { &mut self.robot.backpack }
+}
+
+type Line<'a> = (usize, Vec<&'a str>);
+//the statements of a block and the "end" or "else" that closed it, with its line
+type Block<'a> = (Vec<Statement>, Option<(usize, &'a str)>);
+
+fn parse_script(text: &str) -> Result<Vec<Statement>, String> {
+    let mut lines = text
+        .lines()
+        .enumerate()
+        .map(|(index, line)| (index + 1, line.split('#').next().unwrap_or("").split_whitespace().collect::<Vec<_>>()))
+        .filter(|(_, words)| !words.is_empty());
+
+    match parse_block(&mut lines)? {
+        (script, None) => Ok(script),
+        (_, Some((line, word))) => Err(format!("line {line}: \"{word}\" doesn't close any block")),
+    }
+}
+
+//parses statements up to the "end" or "else" closing the block
+fn parse_block<'a>(lines: &mut impl Iterator<Item = Line<'a>>) -> Result<Block<'a>, String> {
+    let mut block = vec![];
+    while let Some((line, words)) = lines.next() {
+        let statement = match words[..] {
+            ["end"] | ["else"] => return Ok((block, Some((line, words[0])))),
+            ["repeat"] => Statement::Repeat(None, parse_body(lines, line)?),
+            ["repeat", times] => Statement::Repeat(Some(parse_number(line, times)?), parse_body(lines, line)?),
+            ["while", ref condition @ ..] => {
+                Statement::While(Rc::new(parse_condition(line, condition)?), parse_body(lines, line)?)
+            }
+            ["if", ref condition @ ..] => {
+                let condition = parse_condition(line, condition)?;
+                let (then, end) = parse_block(lines)?;
+                let otherwise = match end {
+                    Some((_, "else")) => parse_body(lines, line)?,
+                    Some(_) => Rc::new(vec![]),
+                    None => return Err(format!("line {line}: block without \"end\"")),
+                };
+                Statement::If(condition, Rc::new(then), otherwise)
+            }
+            _ => Statement::Action(parse_action(line, &words)?),
+        };
+        block.push(statement);
+    }
+    Ok((block, None))
+}
+
+//a block that must be closed by "end"
+fn parse_body<'a>(lines: &mut impl Iterator<Item = Line<'a>>, opened: usize) -> Result<Rc<Vec<Statement>>, String> {
+    match parse_block(lines)? {
+        (block, Some((_, "end"))) => Ok(Rc::new(block)),
+        (_, Some((line, word))) => Err(format!("line {line}: unexpected \"{word}\"")),
+        (_, None) => Err(format!("line {opened}: block without \"end\"")),
+    }
+}
+
+fn parse_action(line: usize, words: &[&str]) -> Result<Action, String> {
+    let action = match words {
+        ["go", direction] => Action::Go(parse_direction(line, direction)?),
+        ["destroy", direction] => Action::Destroy(parse_direction(line, direction)?),
+        ["put", content, quantity, direction] => Action::Put(
+            parse_content(line, content)?,
+            parse_number(line, quantity)?,
+            parse_direction(line, direction)?,
+        ),
+        ["craft", content] => Action::Craft(parse_content(line, content)?),
+        ["teleport", row, col] => Action::Teleport(parse_number(line, row)?, parse_number(line, col)?),
+        ["discover", tiles @ ..] if !tiles.is_empty() && tiles.len() % 2 == 0 => Action::Discover(
+            tiles
+                .chunks(2)
+                .map(|tile| Ok((parse_number(line, tile[0])?, parse_number(line, tile[1])?)))
+                .collect::<Result<_, String>>()?,
+        ),
+        ["sell", contents @ ..] if !contents.is_empty() => Action::Sell(
+            contents.iter().map(|content| parse_content(line, content)).collect::<Result<_, _>>()?,
+        ),
+        ["wait"] => Action::Wait,
+        _ => return Err(format!("line {line}: unknown command \"{}\"", words.join(" "))),
+    };
+    Ok(action)
+}
+
+fn parse_condition(line: usize, words: &[&str]) -> Result<Condition, String> {
+    let condition = match words {
+        ["not", condition @ ..] => Condition::Not(Box::new(parse_condition(line, condition)?)),
+        ["energy", comparison, value] => {
+            Condition::Energy(parse_comparison(line, comparison)?, parse_number(line, value)?)
+        }
+        ["backpack", content, comparison, value] => Condition::Backpack(
+            parse_content(line, content)?,
+            parse_comparison(line, comparison)?,
+            parse_number(line, value)?,
+        ),
+        ["weather", "is", weather] => Condition::WeatherIs(
+            WeatherType::iter()
+                .find(|variant| format!("{variant:?}").eq_ignore_ascii_case(weather))
+                .ok_or(format!("line {line}: unknown weather \"{weather}\""))?,
+        ),
+        [direction, "is", content] => {
+            Condition::ContentIs(parse_direction(line, direction)?, parse_content(line, content)?)
+        }
+        _ => return Err(format!("line {line}: invalid condition \"{}\"", words.join(" "))),
+    };
+    Ok(condition)
+}
+
+fn parse_number(line: usize, word: &str) -> Result<usize, String> {
+    word.parse().map_err(|_| format!("line {line}: invalid number \"{word}\""))
+}
+
+fn parse_direction(line: usize, word: &str) -> Result<Direction, String> {
+    Direction::iter()
+        .find(|direction| format!("{direction:?}").eq_ignore_ascii_case(word))
+        .ok_or(format!("line {line}: unknown direction \"{word}\""))
+}
+
+//the default value of the content, the one used as key by the backpack
+fn parse_content(line: usize, word: &str) -> Result<Content, String> {
+    Content::iter()
+        .find(|content| content.to_string().eq_ignore_ascii_case(word))
+        .ok_or(format!("line {line}: unknown content \"{word}\""))
+}
+
+fn parse_comparison(line: usize, word: &str) -> Result<Comparison, String> {
+    match word {
+        "<" => Ok(Comparison::Less),
+        "<=" => Ok(Comparison::LessOrEqual),
+        ">" => Ok(Comparison::Greater),
+        ">=" => Ok(Comparison::GreaterOrEqual),
+        "==" => Ok(Comparison::Equal),
+        "!=" => Ok(Comparison::NotEqual),
+        _ => Err(format!("line {line}: unknown comparison \"{word}\"")),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::RefCell;
+    use robotics_lib::runner::Runner;
+    use crate::test_world::TestWorld;
+    use super::*;
+
+    //runs the script and writes down the action it picks in every tick
+    struct Probe {
+        script: ScriptRobot,
+        actions: Rc<RefCell<Vec<String>>>,
+    }
+
+    impl Runnable for Probe {
+        fn process_tick(&mut self, world: &mut World) {
+            let action = self.script.next_action(world);
+            self.actions.borrow_mut().push(format!("{action:?}"));
+            if let Some(action) = action {
+                self.script.act(action, world);
+            }
+        }
+
+        fn handle_event(&mut self, _event: Event) {}
+        fn get_energy(&self) -> &Energy { self.script.get_energy() }
+        fn get_energy_mut(&mut self) -> &mut Energy { self.script.get_energy_mut() }
+        fn get_coordinate(&self) -> &Coordinate { self.script.get_coordinate() }
+        fn get_coordinate_mut(&mut self) -> &mut Coordinate { self.script.get_coordinate_mut() }
+        fn get_backpack(&self) -> &BackPack { self.script.get_backpack() }
+        fn get_backpack_mut(&mut self) -> &mut BackPack { self.script.get_backpack_mut() }
+    }
+
+    fn run(script: &str, ticks: usize) -> Vec<String> {
+        let actions = Rc::new(RefCell::new(Vec::new()));
+        let probe = Probe { script: ScriptRobot::new(script).unwrap(), actions: Rc::clone(&actions) };
+        let mut runner = Runner::new(Box::new(probe), &mut TestWorld).unwrap();
+        for _ in 0..ticks {
+            runner.game_tick().unwrap();
+        }
+        let actions = actions.borrow().clone();
+        actions
+    }
+
+    #[test]
+    fn repeat_runs_the_block_the_given_times() {
+        let script = "repeat 2\n  go down\n  wait\nend\nrepeat 0\n  go up\nend\ngo right";
+        assert_eq!(run(script, 6), ["Some(Go(Down))", "Some(Wait)", "Some(Go(Down))", "Some(Wait)", "Some(Go(Right))", "None"]);
+        assert_eq!(run("repeat\n  wait\nend", 3), ["Some(Wait)"; 3]);
+    }
+
+    #[test]
+    fn while_checks_the_condition_after_every_run() {
+        let script = "while backpack rock < 2\n  destroy right\n  go right\nend\nwait";
+        assert_eq!(
+            run(script, 6),
+            ["Some(Destroy(Right))", "Some(Go(Right))", "Some(Destroy(Right))", "Some(Go(Right))", "Some(Wait)", "None"]
+        );
+        assert_eq!(run("while energy > 100000\n  wait\nend\ngo down", 1), ["Some(Go(Down))"]);
+    }
+
+    #[test]
+    fn if_picks_a_branch() {
+        let script = "if right is rock\n  destroy right\nelse\n  go down\nend\nif not right is rock\n  go right\nend\nif weather is sunny\n  wait\nelse\n  go down\nend";
+        assert_eq!(run(script, 4), ["Some(Destroy(Right))", "Some(Go(Right))", "Some(Wait)", "None"]);
+        //the tile up is outside of the map
+        assert_eq!(run("if up is none\n  wait\nelse\n  go down\nend", 1), ["Some(Go(Down))"]);
+    }
+
+    #[test]
+    fn loops_without_actions_end_the_tick() {
+        assert_eq!(run("repeat\nend\ngo down", 2), ["None", "None"]);
+        assert_eq!(run("while energy >= 0\n  if energy > 100000\n    wait\n  end\nend", 2), ["None", "None"]);
+    }
+
+    #[test]
+    fn parse_errors_tell_the_line() {
+        let error = |script| ScriptRobot::new(script).err();
+        assert_eq!(error("go sideways"), Some("line 1: unknown direction \"sideways\"".to_string()));
+        assert_eq!(error("# a comment\n\nfly"), Some("line 3: unknown command \"fly\"".to_string()));
+        assert_eq!(error("put rock x down"), Some("line 1: invalid number \"x\"".to_string()));
+        assert_eq!(error("craft gold"), Some("line 1: unknown content \"gold\"".to_string()));
+        assert_eq!(error("while energy >> 3\nend"), Some("line 1: unknown comparison \">>\"".to_string()));
+        assert_eq!(error("if weather is snowy\nend"), Some("line 1: unknown weather \"snowy\"".to_string()));
+        assert_eq!(error("if energy\nend"), Some("line 1: invalid condition \"energy\"".to_string()));
+        assert_eq!(error("repeat 2\n  go up"), Some("line 1: block without \"end\"".to_string()));
+        assert_eq!(error("wait\nend"), Some("line 2: \"end\" doesn't close any block".to_string()));
+        assert_eq!(error("if up is rock\nelse\nelse\nend"), Some("line 3: unexpected \"else\"".to_string()));
+        assert!(error("discover 1 2 3").is_some());
+        assert!(error("").is_none());
+    }
+}
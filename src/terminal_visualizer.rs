@@ -0,0 +1,365 @@
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::rc::Rc;
+use std::time::Instant;
+use cursive::event::Event as CursiveEvent;
+use cursive::theme::{BaseColor, Color, ColorStyle};
+use cursive::traits::{Nameable, Resizable};
+use cursive::views::{DummyView, LinearLayout, Panel, TextView};
+use cursive::{Printer, Vec2, View};
+use robotics_lib::event::events::Event;
+use robotics_lib::interface::{get_score, look_at_sky, robot_map};
+use robotics_lib::runner::{Runnable, Runner};
+use robotics_lib::utils::LibError;
+use robotics_lib::world::environmental_conditions::EnvironmentalConditions;
+use robotics_lib::world::tile::{Content, Tile, TileType};
+use robotics_lib::world::world_generator::Generator;
+use crate::observed_robot::ObservedRobot;
+use crate::replay::Replay;
+
+const REFRESH_RATE: u32 = 30;
+const DEFAULT_TICK_SPEED: f32 = 4.0;
+const MIN_TICK_SPEED: f32 = 0.5;
+const MAX_TICK_SPEED: f32 = 240.0;
+//a slow terminal shouldn't make the game run a burst of ticks to catch up
+const MAX_TICKS_PER_REFRESH: usize = 8;
+
+//what the robot knows about the world, copied after every tick
+#[derive(Default)]
+struct Snapshot {
+    robot_map: Option<Vec<Vec<Option<Tile>>>>,
+    robot_position: (usize, usize),
+    energy: usize,
+    backpack_size: usize,
+    backpack: HashMap<Content, usize>,
+    score: f32,
+    environmental_conditions: Option<EnvironmentalConditions>,
+    //only filled when watching a replay
+    events: Vec<String>,
+}
+
+impl Snapshot {
+    fn from_replay(replay: &mut Replay, tick: usize) -> Self {
+        let robot_map = replay.robot_map_at(tick);
+        let events = replay.events_at(tick).iter().map(Event::to_string).collect();
+        let record = &replay.ticks[tick];
+
+        Self {
+            robot_map: Some(robot_map),
+            robot_position: record.robot_position,
+            energy: record.energy,
+            backpack_size: record.backpack_size,
+            backpack: replay.backpack_at(tick),
+            score: record.score,
+            environmental_conditions: Some(record.environmental_conditions.clone()),
+            events,
+        }
+    }
+}
+
+fn tile_color(tile_type: &TileType) -> Color {
+    match tile_type {
+        TileType::DeepWater => Color::Dark(BaseColor::Blue),
+        TileType::ShallowWater => Color::Light(BaseColor::Blue),
+        TileType::Sand => Color::Light(BaseColor::Yellow),
+        TileType::Grass => Color::Dark(BaseColor::Green),
+        TileType::Street => Color::Light(BaseColor::Black),
+        TileType::Hill => Color::Light(BaseColor::Green),
+        TileType::Mountain => Color::Dark(BaseColor::White),
+        TileType::Snow => Color::Light(BaseColor::White),
+        TileType::Lava => Color::Dark(BaseColor::Red),
+        TileType::Teleport(_) => Color::Dark(BaseColor::Magenta),
+        TileType::Wall => Color::Dark(BaseColor::Black),
+    }
+}
+
+fn content_glyph(content: &Content) -> Option<char> {
+    match content {
+        Content::Rock(_) => Some('r'),
+        Content::Tree(_) => Some('T'),
+        Content::Garbage(_) => Some('g'),
+        Content::Fire => Some('F'),
+        Content::Coin(_) => Some('$'),
+        Content::Bin(_) => Some('b'),
+        Content::Crate(_) => Some('c'),
+        Content::Bank(_) => Some('B'),
+        Content::Water(_) => Some('w'),
+        Content::Market(_) => Some('M'),
+        Content::Fish(_) => Some('f'),
+        Content::Building => Some('H'),
+        Content::Bush(_) => Some('o'),
+        Content::JollyBlock(_) => Some('J'),
+        Content::Scarecrow => Some('S'),
+        Content::None => None,
+    }
+}
+
+//draws the part of the robot_map around the robot, every tile takes two cells so the map isn't
+//stretched vertically
+struct MapView {
+    snapshot: Rc<RefCell<Snapshot>>,
+}
+
+impl View for MapView {
+    fn draw(&self, printer: &Printer) {
+        let snapshot = self.snapshot.borrow();
+        let Some(robot_map) = &snapshot.robot_map else {
+            printer.print((0, 0), "Waiting for the first tick...");
+            return;
+        };
+
+        let (robot_row, robot_col) = snapshot.robot_position;
+        let rows = printer.size.y;
+        let cols = printer.size.x / 2;
+        for y in 0..rows {
+            for x in 0..cols {
+                //screen cell to map tile, centered on the robot
+                let (Some(row), Some(col)) = ((robot_row + y).checked_sub(rows / 2), (robot_col + x).checked_sub(cols / 2)) else {
+                    continue;
+                };
+                let Some(Some(tile)) = robot_map.get(row).and_then(|map_row| map_row.get(col)) else {
+                    continue;
+                };
+
+                let glyph = if (row, col) == (robot_row, robot_col) {
+                    '@'
+                } else {
+                    content_glyph(&tile.content).unwrap_or(' ')
+                };
+                let style = ColorStyle::new(Color::Light(BaseColor::White), tile_color(&tile.tile_type));
+                printer.with_color(style, |printer| printer.print((x * 2, y), &format!("{glyph} ")));
+            }
+        }
+    }
+
+    fn required_size(&mut self, constraint: Vec2) -> Vec2 {
+        constraint
+    }
+}
+
+struct Controls {
+    paused: bool,
+    //ticks per second
+    tick_speed: f32,
+    last_tick: Instant,
+    ticks: usize,
+    error: Option<LibError>,
+}
+
+fn status_text(snapshot: &Snapshot, controls: &Controls) -> String {
+    let state = if controls.paused { "paused" } else { "running" };
+    let mut text = format!(
+        "Tick: {}\n{state}, {} ticks/s\n\nScore: {:.2}\nEnergy: {}\n",
+        controls.ticks, controls.tick_speed, snapshot.score, snapshot.energy
+    );
+
+    if let Some(environmental_conditions) = &snapshot.environmental_conditions {
+        text += &format!(
+            "Weather: {:?}\nTime: {}\n",
+            environmental_conditions.get_weather_condition(),
+            environmental_conditions.get_time_of_day_string()
+        );
+    }
+
+    let mut backpack: Vec<String> = snapshot
+        .backpack
+        .iter()
+        .filter(|(_, amount)| **amount > 0)
+        .map(|(content, amount)| format!("  {content}: {amount}"))
+        .collect();
+    backpack.sort();
+    text += &format!("\nBackpack ({}):\n{}\n", snapshot.backpack_size, backpack.join("\n"));
+
+    if !snapshot.events.is_empty() {
+        text += &format!("\nEvents:\n{}\n", snapshot.events.join("\n"));
+    }
+    if let Some(error) = &controls.error {
+        text += &format!("\nLast error: {error:?}\n");
+    }
+    text
+}
+
+const HELP_TEXT: &str = "space: pause/resume\ns: step\n+/-: speed\nq: quit";
+const REPLAY_HELP_TEXT: &str = "space: pause/resume\ns: step\nb: step back\n+/-: speed\nq: quit";
+
+enum Source {
+    Live(Runner),
+    //the number of ticks shown is kept in Controls
+    Replay(Replay),
+}
+
+impl Source {
+    //moves the game forward by one tick
+    fn step(&mut self, controls: &mut Controls, snapshot: &RefCell<Snapshot>) {
+        match self {
+            Source::Live(runner) => match runner.game_tick() {
+                Ok(()) => controls.ticks += 1,
+                Err(error) => {
+                    //keep the error on screen instead of running into it again
+                    controls.error = Some(error);
+                    controls.paused = true;
+                }
+            },
+            Source::Replay(replay) => {
+                if controls.ticks < replay.ticks.len() {
+                    *snapshot.borrow_mut() = Snapshot::from_replay(replay, controls.ticks);
+                    controls.ticks += 1;
+                } else {
+                    controls.paused = true;
+                }
+            }
+        }
+        controls.last_tick = Instant::now();
+    }
+
+    //only replays can go back
+    fn step_back(&mut self, controls: &mut Controls, snapshot: &RefCell<Snapshot>) {
+        if let Source::Replay(replay) = self {
+            if controls.ticks > 1 {
+                controls.ticks -= 1;
+                *snapshot.borrow_mut() = Snapshot::from_replay(replay, controls.ticks - 1);
+            }
+        }
+    }
+}
+
+//text-mode visualizer, works where no window can be opened, like over ssh. It can run a game or
+//play back a replay file
+pub struct TerminalVisualizer {
+    source: Source,
+    snapshot: Rc<RefCell<Snapshot>>,
+    tick_speed: f32,
+}
+
+impl TerminalVisualizer {
+    //tick_speed is the starting number of ticks per second, it can be changed while the game runs
+    pub fn new(robot: Box<dyn Runnable>, generator: &mut impl Generator, tick_speed: Option<f32>) -> Result<Self, LibError> {
+        let snapshot = Rc::new(RefCell::new(Snapshot::default()));
+        let robot = ObservedRobot::new(robot, {
+            let snapshot = Rc::clone(&snapshot);
+            move |robot, world, _energy_consumed| {
+                let mut snapshot = snapshot.borrow_mut();
+                snapshot.robot_map = robot_map(world);
+                snapshot.robot_position = (robot.get_coordinate().get_row(), robot.get_coordinate().get_col());
+                snapshot.energy = robot.get_energy().get_energy_level();
+                snapshot.backpack_size = robot.get_backpack().get_size();
+                snapshot.backpack = robot.get_backpack().get_contents().clone();
+                snapshot.score = get_score(world);
+                snapshot.environmental_conditions = Some(look_at_sky(world));
+            }
+        });
+
+        Ok(Self {
+            source: Source::Live(Runner::new(Box::new(robot), generator)?),
+            snapshot,
+            tick_speed: tick_speed.unwrap_or(DEFAULT_TICK_SPEED).clamp(MIN_TICK_SPEED, MAX_TICK_SPEED),
+        })
+    }
+
+    pub fn replay(replay: Replay, tick_speed: Option<f32>) -> Self {
+        Self {
+            source: Source::Replay(replay),
+            snapshot: Rc::new(RefCell::new(Snapshot::default())),
+            tick_speed: tick_speed.unwrap_or(DEFAULT_TICK_SPEED).clamp(MIN_TICK_SPEED, MAX_TICK_SPEED),
+        }
+    }
+
+    pub fn run(self) {
+        let help_text = match self.source {
+            Source::Live(_) => HELP_TEXT,
+            Source::Replay(_) => REPLAY_HELP_TEXT,
+        };
+        let source = Rc::new(RefCell::new(self.source));
+        let snapshot = self.snapshot;
+        let controls = Rc::new(RefCell::new(Controls {
+            paused: false,
+            tick_speed: self.tick_speed,
+            last_tick: Instant::now(),
+            ticks: 0,
+            error: None,
+        }));
+
+        let mut siv = cursive::default();
+        siv.add_fullscreen_layer(
+            LinearLayout::horizontal()
+            .child(Panel::new(MapView { snapshot: Rc::clone(&snapshot) }).title("Robot map").full_screen())
+            .child(
+                LinearLayout::vertical()
+                .child(Panel::new(TextView::new("").with_name("status")).title("Status"))
+                .child(DummyView)
+                .child(Panel::new(TextView::new(help_text)).title("Keys"))
+                .fixed_width(30)
+            )
+        );
+
+        let tick = {
+            let source = Rc::clone(&source);
+            let controls = Rc::clone(&controls);
+            let snapshot = Rc::clone(&snapshot);
+            move || source.borrow_mut().step(&mut controls.borrow_mut(), &snapshot)
+        };
+
+        siv.add_global_callback('q', |s| s.quit());
+        siv.add_global_callback(' ', {
+            let controls = Rc::clone(&controls);
+            move |_| {
+                let mut controls = controls.borrow_mut();
+                controls.paused = !controls.paused;
+                controls.last_tick = Instant::now();
+            }
+        });
+        siv.add_global_callback('s', {
+            let controls = Rc::clone(&controls);
+            let tick = tick.clone();
+            move |_| {
+                if controls.borrow().paused {
+                    tick();
+                }
+            }
+        });
+        siv.add_global_callback('b', {
+            let source = Rc::clone(&source);
+            let controls = Rc::clone(&controls);
+            let snapshot = Rc::clone(&snapshot);
+            move |_| {
+                let mut controls = controls.borrow_mut();
+                controls.paused = true;
+                source.borrow_mut().step_back(&mut controls, &snapshot);
+            }
+        });
+        siv.add_global_callback('+', {
+            let controls = Rc::clone(&controls);
+            move |_| {
+                let mut controls = controls.borrow_mut();
+                controls.tick_speed = (controls.tick_speed * 2.0).min(MAX_TICK_SPEED);
+            }
+        });
+        siv.add_global_callback('-', {
+            let controls = Rc::clone(&controls);
+            move |_| {
+                let mut controls = controls.borrow_mut();
+                controls.tick_speed = (controls.tick_speed / 2.0).max(MIN_TICK_SPEED);
+            }
+        });
+        siv.add_global_callback(CursiveEvent::Refresh, move |s| {
+            let due_ticks = {
+                let controls = controls.borrow();
+                if controls.paused {
+                    0
+                } else {
+                    (controls.last_tick.elapsed().as_secs_f32() * controls.tick_speed) as usize
+                }
+            };
+            for _ in 0..due_ticks.min(MAX_TICKS_PER_REFRESH) {
+                tick();
+            }
+
+            let text = status_text(&snapshot.borrow(), &controls.borrow());
+            s.call_on_name("status", |view: &mut TextView| view.set_content(text));
+        });
+
+        siv.set_fps(REFRESH_RATE);
+        siv.run();
+    }
+}
+
@@ -0,0 +1,18 @@
+use robotics_lib::world::environmental_conditions::{EnvironmentalConditions, WeatherType};
+use robotics_lib::world::tile::{Content, Tile, TileType};
+use robotics_lib::world::world_generator::{Generator, World};
+
+//a sunny 5x5 grass world for the tests, with the robot in the top left corner and rocks on the rest
+//of the first row
+pub struct TestWorld;
+
+impl Generator for TestWorld {
+    fn gen(&mut self) -> World {
+        let mut map = vec![vec![Tile { tile_type: TileType::Grass, content: Content::None, elevation: 0 }; 5]; 5];
+        for tile in &mut map[0][1..] {
+            tile.content = Content::Rock(1);
+        }
+        let environmental_conditions = EnvironmentalConditions::new(&[WeatherType::Sunny], 15, 12).unwrap();
+        (map, (0, 0), environmental_conditions, 100.0, None)
+    }
+}
@@ -0,0 +1,214 @@
+use std::cmp::Ordering;
+use std::fs;
+use std::io::ErrorKind;
+use std::time::{SystemTime, UNIX_EPOCH};
+use serde::{Deserialize, Serialize};
+use crate::headless::{spawn_game, GameResult};
+use crate::registry::Registry;
+
+pub const LEADERBOARD_FILE: &str = "leaderboard.toml";
+
+pub struct TournamentOptions {
+    pub ticks: usize,
+    pub seeds: Vec<u64>,
+    //empty means every registered robot that doesn't need to be set up first
+    pub robots: Vec<String>,
+    //empty means every registered generator
+    pub world_generators: Vec<String>,
+    pub leaderboard: String,
+}
+
+impl Default for TournamentOptions {
+    fn default() -> Self {
+        Self {
+            ticks: 1000,
+            seeds: vec![0, 1, 2, 3, 4],
+            robots: vec![],
+            world_generators: vec![],
+            leaderboard: LEADERBOARD_FILE.to_string(),
+        }
+    }
+}
+
+impl TournamentOptions {
+    //parses "--ticks N", "--seeds a,b,c", "--robots a,b", "--generators a,b" and "--leaderboard file",
+    //everything else is ignored
+    pub fn from_args(args: &[String]) -> Result<Self, String> {
+        let mut options = Self::default();
+        let mut args = args.iter();
+
+        while let Some(arg) = args.next() {
+            let mut value = || args.next().ok_or(format!("{arg} needs a value"));
+            match arg.as_str() {
+                "--ticks" => {
+                    let value = value()?;
+                    options.ticks = value.parse().map_err(|_| format!("invalid tick count: {value}"))?;
+                }
+                "--seeds" => {
+                    options.seeds = value()?
+                        .split(',')
+                        .map(|seed| seed.trim().parse().map_err(|_| format!("invalid seed: {seed}")))
+                        .collect::<Result<_, _>>()?;
+                }
+                "--robots" => options.robots = value()?.split(',').map(|name| name.trim().to_string()).collect(),
+                "--generators" => options.world_generators = value()?.split(',').map(|name| name.trim().to_string()).collect(),
+                "--leaderboard" => options.leaderboard = value()?.clone(),
+                _ => {}
+            }
+        }
+
+        Ok(options)
+    }
+}
+
+//the results of a robot over every world of the tournament
+#[derive(Serialize, Deserialize, Clone)]
+struct Standing {
+    rank: usize,
+    robot: String,
+    //mean of score / max_score over the games
+    normalized_score: f64,
+    //score for every 1000 energy consumed, first tie-breaker
+    energy_efficiency: f64,
+    //mean tiles discovered per game, second tie-breaker
+    discovered_tiles: f64,
+    games: usize,
+    //games that didn't finish, they count as 0 in the means
+    errors: usize,
+}
+
+impl Standing {
+    fn new(robot: &str, results: &[Result<GameResult, String>]) -> Self {
+        let games = results.len();
+        let finished = results.iter().filter_map(|result| result.as_ref().ok());
+
+        let mut normalized_score = 0.0;
+        let mut score = 0.0;
+        let mut energy_consumed = 0;
+        let mut discovered_tiles = 0;
+        for result in finished.clone() {
+            if result.max_score > 0.0 {
+                normalized_score += (result.score / result.max_score) as f64;
+            }
+            score += result.score as f64;
+            energy_consumed += result.energy_consumed;
+            discovered_tiles += result.discovered_tiles;
+        }
+
+        Self {
+            rank: 0,
+            robot: robot.to_string(),
+            normalized_score: normalized_score / games.max(1) as f64,
+            energy_efficiency: if energy_consumed > 0 { score * 1000.0 / energy_consumed as f64 } else { 0.0 },
+            discovered_tiles: discovered_tiles as f64 / games.max(1) as f64,
+            games,
+            errors: games - finished.count(),
+        }
+    }
+
+    //best first
+    fn compare(&self, other: &Self) -> Ordering {
+        other
+            .normalized_score
+            .total_cmp(&self.normalized_score)
+            .then(other.energy_efficiency.total_cmp(&self.energy_efficiency))
+            .then(other.discovered_tiles.total_cmp(&self.discovered_tiles))
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+struct Tournament {
+    //seconds since the unix epoch
+    timestamp: u64,
+    ticks: usize,
+    //toml integers are signed, a u64 seed doesn't always fit
+    seeds: Vec<String>,
+    world_generators: Vec<String>,
+    standings: Vec<Standing>,
+}
+
+//every tournament ever played, the newest last
+#[derive(Serialize, Deserialize, Default)]
+struct Leaderboard {
+    #[serde(default)]
+    tournaments: Vec<Tournament>,
+}
+
+fn read_leaderboard(path: &str) -> Result<Leaderboard, String> {
+    match fs::read_to_string(path) {
+        Ok(text) => toml::from_str(&text).map_err(|error| format!("Error reading {path}: {error}")),
+        //no tournament has been played yet
+        Err(error) if error.kind() == ErrorKind::NotFound => Ok(Leaderboard::default()),
+        Err(error) => Err(format!("Error reading {path}: {error}")),
+    }
+}
+
+pub fn run_tournament(registry: &Registry, options: &TournamentOptions) -> Result<(), String> {
+    //read before playing, a broken leaderboard shouldn't waste a whole tournament
+    let mut leaderboard = read_leaderboard(&options.leaderboard)?;
+
+    let robots: Vec<String> = if options.robots.is_empty() {
+        registry.robots().iter().filter(|entry| !entry.needs_setup).map(|entry| entry.name.to_string()).collect()
+    } else {
+        options.robots.clone()
+    };
+    let world_generators = if options.world_generators.is_empty() {
+        registry.world_generators().iter().map(|entry| entry.name.to_string()).collect()
+    } else {
+        options.world_generators.clone()
+    };
+
+    let mut standings = Vec::new();
+    for robot in &robots {
+        let mut results = Vec::new();
+        for world_generator in &world_generators {
+            for seed in &options.seeds {
+                eprintln!("Playing {robot} on {world_generator} seed {seed}");
+                let result = spawn_game(robot, world_generator, *seed, options.ticks);
+                if let Err(error) = &result {
+                    eprintln!("Error playing {robot} on {world_generator} seed {seed}: {error}");
+                }
+                results.push(result);
+            }
+        }
+        standings.push(Standing::new(robot, &results));
+    }
+
+    standings.sort_by(Standing::compare);
+    for (index, standing) in standings.iter_mut().enumerate() {
+        standing.rank = index + 1;
+    }
+
+    let previous = leaderboard.tournaments.last();
+    println!(
+        "{:>4} {:<6} {:>10} {:>10} {:>10} {:>6} {:>6}  {}",
+        "rank", "robot", "score", "efficiency", "discovered", "games", "errors", "previous rank"
+    );
+    for standing in &standings {
+        let previous_rank = previous
+            .and_then(|tournament| tournament.standings.iter().find(|previous| previous.robot == standing.robot))
+            .map(|previous| previous.rank.to_string())
+            .unwrap_or("-".to_string());
+        println!(
+            "{:>4} {:<6} {:>10.4} {:>10.2} {:>10.1} {:>6} {:>6}  {}",
+            standing.rank,
+            standing.robot,
+            standing.normalized_score,
+            standing.energy_efficiency,
+            standing.discovered_tiles,
+            standing.games,
+            standing.errors,
+            previous_rank
+        );
+    }
+
+    leaderboard.tournaments.push(Tournament {
+        timestamp: SystemTime::now().duration_since(UNIX_EPOCH).map(|time| time.as_secs()).unwrap_or(0),
+        ticks: options.ticks,
+        seeds: options.seeds.iter().map(u64::to_string).collect(),
+        world_generators,
+        standings,
+    });
+    let text = toml::to_string_pretty(&leaderboard).map_err(|error| format!("Error saving the leaderboard: {error}"))?;
+    fs::write(&options.leaderboard, text).map_err(|error| format!("Error writing {}: {error}", options.leaderboard))
+}
@@ -0,0 +1,70 @@
+use std::fs::File;
+use std::io::{BufReader, BufWriter};
+use midgard::params::WorldGeneratorParameters;
+use robotics_lib::world::world_generator::{Generator, World};
+use serde::{Deserialize, Serialize};
+use crate::registry::{GeneratorWrapper, Registry};
+
+//a world generator selection starting with this is the path of a world file instead of the name of
+//a registered generator, like "file:worlds/island.world"
+pub const WORLD_FILE_PREFIX: &str = "file:";
+
+//bumped whenever the layout of the file changes, old files are refused instead of misread
+const WORLD_FILE_VERSION: u32 = 1;
+
+#[derive(Serialize, Deserialize)]
+struct WorldFile {
+    version: u32,
+    world: World,
+}
+
+pub fn save_world(world: &World, path: &str) -> Result<(), String> {
+    let file = File::create(path).map_err(|error| format!("Error creating {path}: {error}"))?;
+    let world_file = WorldFile { version: WORLD_FILE_VERSION, world: world.clone() };
+
+    bincode::serialize_into(BufWriter::new(file), &world_file).map_err(|error| format!("Error writing {path}: {error}"))
+}
+
+pub fn load_world(path: &str) -> Result<World, String> {
+    let file = File::open(path).map_err(|error| format!("Error opening {path}: {error}"))?;
+    let world_file: WorldFile = bincode::deserialize_from(BufReader::new(file))
+        .map_err(|error| format!("{path} is not a valid world file: {error}"))?;
+
+    if world_file.version != WORLD_FILE_VERSION {
+        return Err(format!("{path} was saved with world file version {}, expected {WORLD_FILE_VERSION}", world_file.version));
+    }
+    Ok(world_file.world)
+}
+
+//runs the generator once and saves everything it returned
+pub fn export_world(generator: &mut impl Generator, path: &str) -> Result<(), String> {
+    save_world(&generator.gen(), path)
+}
+
+//gives back the world saved in a file, every time gen is called
+pub struct FileGenerator {
+    world: World,
+}
+
+impl FileGenerator {
+    //the file is read here, so a missing or broken file is reported before the game starts
+    pub fn new(path: &str) -> Result<Self, String> {
+        Ok(Self { world: load_world(path)? })
+    }
+}
+
+impl Generator for FileGenerator {
+    fn gen(&mut self) -> World { self.world.clone() }
+}
+
+//builds a registered generator or a FileGenerator, depending on the selection
+pub fn build_world_generator(registry: &Registry, selection: &str, params: &WorldGeneratorParameters) -> Result<GeneratorWrapper, String> {
+    if let Some(path) = selection.strip_prefix(WORLD_FILE_PREFIX) {
+        return Ok(GeneratorWrapper::new(Box::new(FileGenerator::new(path)?)));
+    }
+
+    registry
+        .world_generator(selection)
+        .map(|entry| entry.build(params))
+        .ok_or(format!("Unknown world generator: {selection}"))
+}
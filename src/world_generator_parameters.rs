@@ -0,0 +1,115 @@
+use midgard::params::{ContentsRadii, WorldGeneratorParameters};
+
+//world size used when the user doesn't pick one
+pub const DEFAULT_WORLD_SIZE: usize = 200;
+
+pub struct RadiusField {
+    pub name: &'static str,
+    pub get: fn(&ContentsRadii) -> u64,
+    pub set: fn(&mut ContentsRadii, u64),
+}
+
+//every field of ContentsRadii, used to build the settings dialog and to read the radii from the command line
+pub const CONTENTS_RADII_FIELDS: [RadiusField; 18] = [
+    RadiusField { name: "trees_in_forest", get: |r| r.trees_in_forest, set: |r, v| r.trees_in_forest = v },
+    RadiusField { name: "trees_in_hill", get: |r| r.trees_in_hill, set: |r, v| r.trees_in_hill = v },
+    RadiusField { name: "trees_in_mountain", get: |r| r.trees_in_mountain, set: |r, v| r.trees_in_mountain = v },
+    RadiusField { name: "rocks_in_plains", get: |r| r.rocks_in_plains, set: |r, v| r.rocks_in_plains = v },
+    RadiusField { name: "rocks_in_hill", get: |r| r.rocks_in_hill, set: |r, v| r.rocks_in_hill = v },
+    RadiusField { name: "rocks_in_mountain", get: |r| r.rocks_in_mountain, set: |r, v| r.rocks_in_mountain = v },
+    RadiusField { name: "bushes_in_plains", get: |r| r.bushes_in_plains, set: |r, v| r.bushes_in_plains = v },
+    RadiusField { name: "fish_in_shallow_water", get: |r| r.fish_in_shallow_water, set: |r, v| r.fish_in_shallow_water = v },
+    RadiusField { name: "fish_in_deep_water", get: |r| r.fish_in_deep_water, set: |r, v| r.fish_in_deep_water = v },
+    RadiusField { name: "garbage", get: |r| r.garbage, set: |r, v| r.garbage = v },
+    RadiusField { name: "coins", get: |r| r.coins, set: |r, v| r.coins = v },
+    RadiusField { name: "garbage_bins", get: |r| r.garbage_bins, set: |r, v| r.garbage_bins = v },
+    RadiusField { name: "crates", get: |r| r.crates, set: |r, v| r.crates = v },
+    RadiusField { name: "markets", get: |r| r.markets, set: |r, v| r.markets = v },
+    RadiusField { name: "banks", get: |r| r.banks, set: |r, v| r.banks = v },
+    RadiusField { name: "buildings", get: |r| r.buildings, set: |r, v| r.buildings = v },
+    RadiusField { name: "scarecrows", get: |r| r.scarecrows, set: |r, v| r.scarecrows = v },
+    RadiusField { name: "jolly_blocks", get: |r| r.jolly_blocks, set: |r, v| r.jolly_blocks = v },
+];
+
+//the parameters the demo has always used, with a random seed
+pub fn default_parameters() -> WorldGeneratorParameters {
+    WorldGeneratorParameters {
+        world_size: DEFAULT_WORLD_SIZE,
+        amount_of_streets: Some(0.7),
+        ..Default::default()
+    }
+}
+
+fn validate_amount(name: &str, amount: Option<f64>) -> Result<(), String> {
+    match amount {
+        Some(amount) if !amount.is_finite() || amount < 0.0 => Err(format!("{name} must be a positive number")),
+        _ => Ok(()),
+    }
+}
+
+pub fn validate(params: &WorldGeneratorParameters) -> Result<(), String> {
+    if params.world_size < 3 {
+        return Err("world_size must be 3 or more".to_string());
+    }
+    if params.starting_hour > 23 {
+        return Err("starting_hour must be between 0 and 23".to_string());
+    }
+    validate_amount("rivers", params.amount_of_rivers)?;
+    validate_amount("streets", params.amount_of_streets)?;
+    validate_amount("teleports", params.amount_of_teleports)?;
+    if let Some(elevation_multiplier) = params.elevation_multiplier {
+        if !elevation_multiplier.is_finite() || elevation_multiplier <= 0.0 {
+            return Err("elevation_multiplier must be greater than 0".to_string());
+        }
+    }
+    for field in &CONTENTS_RADII_FIELDS {
+        //a radius of 0 would make the poisson distribution never end
+        if (field.get)(&params.contents_radii) == 0 {
+            return Err(format!("{} radius must be 1 or more", field.name));
+        }
+    }
+    Ok(())
+}
+
+fn parse_optional(value: &str) -> Result<Option<f64>, String> {
+    if value == "none" {
+        Ok(None)
+    } else {
+        value.parse().map(Some).map_err(|_| format!("invalid number: {value}"))
+    }
+}
+
+//reads the parameters from "--name value" pairs, like the ones after --export-world.
+//missing parameters keep their default value
+pub fn from_args(args: &[String]) -> Result<WorldGeneratorParameters, String> {
+    let mut params = default_parameters();
+
+    for pair in args.chunks(2) {
+        let [name, value] = pair else {
+            return Err(format!("missing value for {}", pair[0]));
+        };
+        let name = name.trim_start_matches("--");
+        let invalid = || format!("invalid value for {name}: {value}");
+
+        match name {
+            "seed" => params.seed = value.parse().map_err(|_| invalid())?,
+            "world_size" => params.world_size = value.parse().map_err(|_| invalid())?,
+            "always_sunny" => params.always_sunny = value.parse().map_err(|_| invalid())?,
+            "rivers" => params.amount_of_rivers = parse_optional(value)?,
+            "streets" => params.amount_of_streets = parse_optional(value)?,
+            "teleports" => params.amount_of_teleports = parse_optional(value)?,
+            "elevation_multiplier" => params.elevation_multiplier = parse_optional(value)?,
+            "starting_hour" => params.starting_hour = value.parse().map_err(|_| invalid())?,
+            _ => {
+                let field = CONTENTS_RADII_FIELDS
+                    .iter()
+                    .find(|field| field.name == name)
+                    .ok_or(format!("unknown parameter: {name}"))?;
+                (field.set)(&mut params.contents_radii, value.parse().map_err(|_| invalid())?);
+            }
+        }
+    }
+
+    validate(&params)?;
+    Ok(params)
+}